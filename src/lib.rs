@@ -145,17 +145,291 @@
 //!
 //! ## Attributes
 //!
-//! ### Container Attributes (on structs)
+//! ### Container Attributes (on structs and enums)
 //!
 //! - `#[dissolve(visibility = "...")]` - Set the visibility of both the `dissolve` method and the generated dissolved struct
-//!   - Supported values: `"pub"`, `"pub(crate)"`, `"pub(super)"`, `"pub(self)"`, or empty string for private
+//!   - Supported values: `"pub"`, `"pub(crate)"`, `"pub(super)"`, `"pub(self)"`, `"pub(in path)"` (e.g.
+//!     `"pub(in super::super)"`), `"inherit"` to use the visibility of the original struct, or empty
+//!     string for private
 //!   - Default: `"pub"` if not specified
 //!   - Note: The dissolved struct (`{StructName}Dissolved`) will have the same visibility as the `dissolve` method
+//! - `#[dissolve(recompose)]` - Generate an `into_original` method on the dissolved struct that rebuilds the
+//!   original struct (named structs only)
+//!   - Skipped fields are taken as arguments, in declaration order
+//!   - A skipped field can be renamed, which renames its argument
+//!   - Renamed fields are mapped back to their original names
+//! - `#[dissolve(into_tuple)]` - Also implement `From<{StructName}Dissolved>` for a tuple of the included
+//!   fields, in dissolved field order (named structs only)
+//! - `#[dissolve(from_dissolved)]` - Implement `From<{StructName}Dissolved>` for the original struct,
+//!   mapping renamed fields back (named structs only)
+//!   - Requires that no field is skipped or converted with `with` or `cow`, so the dissolved struct
+//!     holds every field as it is
+//! - `#[dissolve(doc_provenance)]` - Append a note to the docs of each dissolved field that was renamed
+//!   or was not public in the original struct, e.g. "Renamed from `id`." (named structs only)
+//! - `#[dissolve(map)]` - Also generate `map_dissolved(self, f)`, which dissolves the struct and returns
+//!   `f` applied to the dissolved value
+//! - `#[dissolve(newtype)]` - Dissolve a struct with a single included field into that field's
+//!   value instead of a one-element tuple or struct, and implement `From<StructName>` for it
+//!   - Named structs then dissolve like tuple structs, so options that build a dissolved struct,
+//!     such as `recompose`, and field renames are rejected
+//!   - `#[repr(transparent)]` on the original struct is left as it is, making `dissolve` the
+//!     `into_inner` of the wrapper
+//!   - The `From` impl is omitted when the field's type is a bare type parameter such as `T` or
+//!     `Box<T>`, which the orphan rules do not allow
+//! - `#[dissolve(prune_generics)]` - Drop generic parameters that are only used by skipped fields from
+//!   the dissolved struct, along with the bounds and where predicates that mention them (named structs
+//!   only)
+//!   - With `recompose`, the pruned parameters become generic parameters of `into_original`
+//!   - Without it, such parameters are kept by a public `_marker`
+//!     [`PhantomData`](core::marker::PhantomData) field on the dissolved struct, which leaves its
+//!     `Send` and `Sync` impls untouched but still bounds those parameters in anything derived for it
+//!     through `derive`, such as `Clone`
+//! - `#[dissolve(inherit_field_vis)]` - Give each field of the dissolved struct the visibility of the
+//!   original field instead of `pub` (named structs only)
+//! - `#[dissolve(fields_visibility = "...")]` - Choose the visibility policy of the dissolved fields
+//!   (named structs only)
+//!   - `"pub"` (default): every dissolved field is `pub`
+//!   - `"mirror"`: each dissolved field keeps the visibility of the original field, like
+//!     `inherit_field_vis`
+//! - `#[dissolve(output = "struct")]` - Dissolve a tuple struct into a named `{StructName}Dissolved`
+//!   struct instead of a tuple, with a `field_{index}` field per included field (tuple structs only)
+//!   - Fields are named after their original index, so skipped fields leave gaps, e.g. `field_0`
+//!     and `field_2` when the second field is skipped
+//!   - The fields then take the options of named struct fields, such as `rename`, and the struct
+//!     the options of named structs, such as `recompose`
+//!   - `output = "tuple"` is the default
+//! - `#[dissolve(rename_all = "transform")]` - Apply `rename_with` transforms to every included
+//!   field that is not renamed itself, e.g. `rename_all = "upper"`
+//!   - Takes the same transforms as `#[dissolved(rename_with = "...")]`, which a field can use to
+//!     override it
+//!   - With `output = "struct"`, renames the `field_{index}` fields of a tuple struct, e.g.
+//!     `rename_all = "strip_prefix:field_|prefix:channel_"` gives `channel_0`, `channel_1`, ...;
+//!     fields of tuple structs and tuple variants are otherwise unnamed, so it does not affect them
+//! - `#[dissolve(array)]` - Dissolve a tuple struct whose included fields all have the same type into
+//!   an array instead of a tuple, e.g. `[u8; 3]` (tuple structs only)
+//!   - Skipped fields are left out, so they shorten the array
+//! - `#[dissolve(test_helpers)]` - Generate a `new` constructor on the dissolved struct taking the
+//!   included fields in declaration order, using their dissolved names (named structs only)
+//!   - Use `#[cfg_attr(test, dissolve(test_helpers))]` to only generate it for tests
+//! - `#[dissolve(manually_drop)]` - Dissolve without running the original struct's `Drop` impl
+//!   - Included fields are returned wrapped in [`ManuallyDrop`](core::mem::ManuallyDrop), so
+//!     dropping them is up to the caller
+//!   - Skipped fields are dropped in place
+//!   - The generated code uses `unsafe` internally, so it cannot be combined with
+//!     `#![forbid(unsafe_code)]`
+//! - `#[dissolve(only("field", ...))]` - Only include the listed fields, skipping all others without
+//!   per-field annotations (named structs only)
+//!   - Every listed name must match a field of the struct
+//! - `#[dissolve(only_public)]` - Only include fields that are more visible than private, such as
+//!   `pub` or `pub(crate)` fields, skipping private fields without per-field annotations (named
+//!   structs only)
+//!   - `skip` and `keep` still apply to the included fields, and `keep` hands back private ones
+//!   - Cannot be combined with `only`
+//! - `#[dissolve(keep_attrs(doc, serde, ...))]` - Choose which field attributes are forwarded to the
+//!   dissolved struct, by attribute path (named structs only)
+//!   - Default: only `doc` comments are forwarded
+//!   - Listing attributes replaces the default, so include `doc` to keep forwarding doc comments
+//! - `#[dissolve(move_strategy = "...")]` - Choose how the `dissolve` body moves fields out of `self`
+//!   - `"field"` (default): one `self.field` move per included field
+//!   - `"destructure"`: a single `let Self { .. } = self;` destructuring pattern
+//! - `#[dissolve(method = "name")]` - Name the generated method `name` instead of `dissolve`
+//!   - Inherent methods cannot be overloaded, so a type that already has a `dissolve` method of
+//!     its own fails with "duplicate definitions with name `dissolve`"; pick another name here
+//!   - Generated code calling the method, such as `map_dissolved` or the `newtype` `From` impl,
+//!     uses the new name, and `dissolve_for!` names its function `{name}_{type_name}`
+//! - `#[dissolve(snapshot)]` - Also generate `snapshot(&self)`, which clones the included fields into
+//!   a new dissolved value without consuming the original (structs only)
+//!   - Use `snapshot = "name"` to name the method differently
+//!   - Field types that mention a type parameter must implement `Clone` for the method to be callable
+//! - `#[dissolve(copy_view)]` - Also generate `dissolve_ref(&self)`, which copies the included
+//!   fields into a new dissolved value, avoiding `snapshot`'s `.clone()` calls for plain-data
+//!   structs (structs only)
+//!   - Every included field must be `Copy`; field types that mention a type parameter get a `Copy`
+//!     bound on the method instead
+//! - `#[dissolve(drain)]` - Also generate `drain(&mut self)`, which takes the included fields
+//!   out with [`mem::take`](core::mem::take) into a new dissolved value, leaving their default
+//!   values in the struct (structs only)
+//!   - Useful to reclaim the contents of a struct while keeping it around; skipped fields keep
+//!     their values
+//!   - Every included field must be `Default`; field types that mention a type parameter get a
+//!     `Default` bound on the method instead
+//! - `#[dissolve(boxed)]` - Return the dissolved struct as `Box<{StructName}Dissolved>`, so that large
+//!   dissolved structs are passed around as a pointer (named structs only)
+//! - `#[dissolve(transparent)]` - Mark the dissolved struct `#[repr(transparent)]`, giving it the
+//!   layout of its only included field (named structs only)
+//! - `#[dissolve(as_ref)]` - Implement `AsRef` for the type of the only included field, borrowing it
+//!   without dissolving the struct (structs only)
+//! - `#[dissolve(derive(Debug, Clone, ...))]` - Derive the listed traits for the dissolved struct or
+//!   enum (not for tuple structs)
+//!   - `Dissolve` itself is rejected: the dissolved type is the terminal form of the original type
+//! - `#[dissolve(assert_size)]` - Check at compile time that the value returned by `dissolve` is
+//!   no larger than the value it dissolves, to catch padding or conversions that bloat it
+//!   - The check is a `const _` item, which `cargo check` evaluates too
+//!   - A `const _` item cannot name the generic parameters of a type, so a generic type is checked
+//!     by an inline `const` block in `dissolve` instead, evaluated when the crate is built rather
+//!     than by `cargo check`, for each instantiation that is used
+//!   - With `boxed`, the returned `Box` is compared, and a carrier is counted with the dissolved
+//!     struct
+//! - `#[dissolve(terminal)]` - Mark the type as already in its terminal form, so the derive
+//!   generates nothing for it
+//!   - Meant for generated pipelines that put `#[derive(Dissolve)]` on every type, including
+//!     hand-written dissolved forms that must not become `{StructName}DissolvedDissolved`
+//!   - Cannot be combined with other options or with `#[dissolved(...)]` field attributes
+//! - `#[dissolve(serde)]` - Add `#[cfg_attr(feature = "serde", derive(serde::Serialize,
+//!   serde::Deserialize))]` to the dissolved struct or enum, and forward `#[serde(...)]` field and
+//!   variant attributes behind the same `cfg_attr` (not for tuple structs)
+//!   - The derive does not declare `serde` as a helper attribute, so `#[serde(...)]` attributes
+//!     are rejected unless the original type derives the serde traits too
+//!   - `feature = "serde"` is a feature of the crate using the derive, which must then depend on
+//!     `serde` with its `derive` feature; this crate does not depend on `serde`
+//!   - Declare the `serde` feature even if it is optional, otherwise the `unexpected_cfgs` lint
+//!     warns about the generated `cfg_attr`
+//! - `#[dissolve(to_json)]` - Also generate `dissolve_to_json(self) -> serde_json::Result<String>`,
+//!   which dissolves the value and serializes the dissolved value to JSON
+//!   - Requires the `serde_json` feature of this crate, e.g.
+//!     `dissolve-derive = { version = "0.1", features = ["serde_json"] }`; without it, the option is
+//!     rejected
+//!   - The crate using the derive must depend on `serde_json` itself; to only generate the method
+//!     with one of its features, write `#[cfg_attr(feature = "serde_json", dissolve(to_json))]`
+//!   - The dissolved type must implement `serde::Serialize`, which `serde` provides
+//!   - The carrier of `keep_for_rebuild` and `keep` fields is not serialized
+//! - `#[dissolve(cfg = "feature = \"extract\"")]` - Only generate the dissolved type and the
+//!   `dissolve` API when the given cfg predicate holds, by putting `#[cfg(...)]` on every generated
+//!   item
+//! - `#[dissolve(boxed_self)]` - Generate `dissolve(self: Box<Self>)` instead of `dissolve(self)`,
+//!   so that a `Box<StructName>` is dissolved directly, e.g. a boxed builder
+//!   - Cannot be combined with the options that dissolve an unboxed value: `map`, `newtype`,
+//!     `dynamic`, `tagged`, `tuple_from`, `from_arc` and `from_rc`
+//! - `#[dissolve(from_arc)]` - Also generate `dissolve_arc(this: Arc<Self>)`, which dissolves the
+//!   value if `this` is its only strong reference and hands `this` back otherwise
+//!   - Other strong references keep the value alive, so it can only be dissolved by its single
+//!     owner: drop the clones first, or retry once they are gone
+//!   - `#[dissolve(from_rc)]` generates `dissolve_rc(this: Rc<Self>)` the same way for `Rc`
+//! - `#[dissolve(method_attrs(inline, ...))]` - Add the listed attributes to the `dissolve` method
+//!   - They come after generated attributes, so they can override the
+//!     `#[allow(clippy::type_complexity)]` put on `dissolve` for tuple structs returning three or
+//!     more fields, e.g. with `method_attrs(warn(clippy::type_complexity))`
+//! - `#[dissolve(track_caller)]` - Put `#[track_caller]` on `dissolve` and on the other generated
+//!   methods that dissolve the value: `map_dissolved`, `dissolve_arc`, `dissolve_rc`,
+//!   `dissolve_to_json`, `dissolve_tagged`, `dissolve_dynamic` and the `transition_to` method
+//!   - A `finalize` hook that is itself `#[track_caller]` then reports the caller of these methods
+//!     as the location of its panics
+//! - `#[dissolve(no_auto_doc)]` - Leave out the generated documentation of the dissolved struct or
+//!   enum and of the `dissolve` method
+//!   - Document the method yourself with `method_attrs(doc = "...")`; the dissolved type is left
+//!     undocumented, which `#![deny(missing_docs)]` reports for public types
+//! - `#[dissolve(field_extractors)]` - Generate an `into_{field}` method on the dissolved struct for
+//!   each included field, returning that field and dropping the others (named structs only)
+//! - `#[dissolve(debug_generated)]` - Print the generated code to stderr while compiling, one item
+//!   per line, to see what the macro expands to
+//!   - The generated code binds fields by their own names, or `field_{index}` for tuple fields,
+//!     so it reads like hand-written code here and in `cargo expand`
+//! - `#[dissolve(nested)]` - Nest fields marked `#[dissolved(group = "...")]` into sub-structs of
+//!   the dissolved struct (named structs only)
+//! - `#[dissolve(opaque)]` - Hide the dissolved struct from the documentation and mark it
+//!   `#[non_exhaustive]`, so that other crates can read its fields but cannot construct it or
+//!   destructure it without `..` (named structs only)
+//!   - `dissolve` still returns the named struct: a fresh struct implements no trait that an
+//!     `impl Trait` return type could name
+//!   - Within the defining crate, the struct can still be constructed
+//! - `#[dissolve(after = "Self::on_dissolve")]` - Call the given function with `&self` once at the
+//!   start of `dissolve`, e.g. to count or log dissolved values
+//!   - It runs before any field is moved out, since `dissolve` consumes `self`
+//! - `#[dissolve(finalize = "check_invariants")]` - Call the given function with a reference to the
+//!   value `dissolve` is about to return, e.g. to log it or check invariants at the dissolve
+//!   boundary
+//!   - The hook only observes the value: it takes `&{StructName}Dissolved` (or whatever `dissolve`
+//!     returns, such as the tuple of a tuple struct) and its result is ignored
+//! - `#[dissolve(dynamic)]` - Also generate `dissolve_dynamic(self)`, returning a
+//!   `HashMap<&'static str, Box<dyn Any>>` with each included field boxed under its dissolved name
+//!   (named structs only)
+//!   - `Any` requires `'static`, so the method is only callable when every included field type is
+//!     `'static`
+//!   - With `keep_for_rebuild`, the carrier is dropped
+//! - `#[dissolve(tagged)]` - Also generate a `{StructName}Field` enum with one variant per included
+//!   field, named after its dissolved name in pascal case, and `dissolve_tagged(self)`, returning
+//!   the included fields as a `Vec` of that enum in declaration order (named structs only)
+//!   - The enum only has the generic parameters used by the included fields, and gets the traits
+//!     listed in `derive`
+//! - `#[dissolve(tuple_from)]` - Implement `From<StructName>` for a tuple of the included field
+//!   types, in declaration order, so the struct converts straight into a tuple (named structs only)
+//!   - Skipped fields are left out and renamed fields keep their position; the impl's documentation
+//!     lists the field at each position
+//! - `#[dissolve(seal)]` - Add a private `_seal: ()` field to the dissolved struct, so that only
+//!   `dissolve` and the module deriving it can build one with a struct literal (named structs only)
+//!   - Patterns need `..` to skip the private field, e.g. `let SessionDissolved { id, .. } =
+//!     session.dissolve();`
+//! - `#[dissolve(transition_to = "NextState", via(field, ...))]` - Also generate
+//!   `into_next_state(self) -> NextState`, which dissolves the struct and builds `NextState` from
+//!   the listed fields (named structs only)
+//!   - Fields are listed by their dissolved names, which must be the field names of `NextState`
+//!   - `NextState` is a path to a struct with exactly the listed fields, e.g.
+//!     `transition_to = "crate::states::Done"`; the method is named after its last segment
+//!
+//! Options can be spread over several `#[dissolve(...)]` attributes, which are merged in order:
+//! list options such as `only`, `keep_attrs`, `derive` and `method_attrs` accumulate, while a later value of any
+//! other option replaces an earlier one.
 //!
 //! ### Field Attributes
 //!
 //! - `#[dissolved(skip)]` - Skip this field in the dissolved output
+//!   - The documentation of a dissolved struct lists the fields skipped from it
+//!   - A lifetime shared with included fields stays on the dissolved struct: skipping an `&'a Arena`
+//!     next to an included `&'a [u8]` drops the reference, while the data still borrows the arena
+//!   - A generic parameter only used by skipped fields is kept by a `_marker` field on the dissolved
+//!     struct, or removed with `prune_generics`; dissolved enums always drop it
+//! - `#[dissolved(skip, keep_for_rebuild)]` - Skip this field, but keep its value for `into_original`
+//!   (named structs with `recompose` only)
+//!   - `dissolve` returns the dissolved struct together with a `{StructName}Carrier` holding the kept
+//!     fields, whose own fields are private
+//!   - `into_original` takes the carrier instead of arguments for these fields
+//! - `#[dissolved(skip, keep)]` - Skip this field in the dissolved struct, but hand its value back
+//!   in the `{StructName}Carrier` returned next to it instead of dropping it (named structs only)
+//!   - `dissolve` returns `(StructNameDissolved, StructNameCarrier)`, and the kept fields are public
+//!     on the carrier (or keep their own visibility with `inherit_field_vis`)
+//!   - Does not need `recompose`; with it, `into_original` takes the carrier back like for
+//!     `keep_for_rebuild`
+//! - `#[dissolved(skip, default)]` - Skip this field, and have `into_original` set it to
+//!   `Default::default()` instead of taking it as an argument (named structs with `recompose` only)
+//!   - `#[dissolved(skip, default = "expr")]` sets it to `expr` instead, e.g.
+//!     `default = "Vec::with_capacity(16)"`
+//!   - Handy for derived state such as caches or validation flags, which a dissolved and edited
+//!     value should not carry back
 //! - `#[dissolved(rename = "new_name")]` - Rename this field in the dissolved struct (named structs only)
+//!   - Keywords are turned into raw identifiers: `rename = "type"` and `rename = "r#type"` are equivalent
+//!   - Skipped fields can only be renamed with `recompose`, which uses the new name for the
+//!     `into_original` argument
+//! - `#[dissolved(rename_with = "transform")]` - Compute the dissolved name from the field name with a
+//!   built-in transform (named structs only)
+//!   - Supported transforms: `upper`, `lower`, `strip_prefix:<prefix>`, `strip_suffix:<suffix>`,
+//!     `prefix:<prefix>` and `suffix:<suffix>`
+//!   - Transforms can be chained with `|` and are applied left to right, e.g.
+//!     `rename_with = "strip_prefix:raw_|suffix:_bytes"`
+//!   - A name that is not snake case, e.g. from `upper`, gets `#[allow(non_snake_case)]` on the
+//!     generated items using it
+//!   - An explicit `rename` on the same field takes precedence
+//! - `#[dissolved(group = "name")]` - Move this field into the `name` field of the dissolved struct,
+//!   a `{StructName}{Name}Dissolved` struct holding every field of the group (named structs with
+//!   `nested` only)
+//!   - The group takes the place of its first field, ungrouped fields stay at the top level
+//! - `#[dissolved(alias = "old_name")]` - Also generate a deprecated `old_name(&self)` method on the
+//!   dissolved struct borrowing this field, to keep a former name working during a migration
+//!   (named structs only)
+//!   - Usually combined with `rename`, e.g. `#[dissolved(rename = "new_name", alias = "old_name")]`
+//! - `#[dissolved(with = "path::to::fn", ty = "Type")]` - Convert this field with the given function
+//!   while dissolving, and give it the type that function returns in the dissolved output
+//!   - Useful for fields that should not be handed out as they are, e.g. turning a
+//!     `Pin<Box<T>>` into a raw pointer to the pinned value
+//!   - The function takes the field by value; both options are required together
+//!   - Cannot be combined with `recompose`, `from_dissolved`, `snapshot`, `copy_view`, `drain` or
+//!     `as_ref`, which need the dissolved value to have the type of the field
+//! - `#[dissolved(cow)]` - Dissolve a `String` field into a `Cow<'static, str>` and a `Vec<T>` field
+//!   into a `Cow<'static, [T]>`, holding the field in `Cow::Owned`
+//!   - Lets the dissolved type share a field type with APIs that accept borrowed or owned data;
+//!     `Cow` is covariant, so the value also fits a `Cow<'a, str>`
+//!   - Other owned types borrow the type they deref to, e.g. `Cow<'static, Path>` for a `PathBuf`
+//!   - The borrowed type must be `'static`, so `Vec<&'a str>` is not supported
+//!   - A shorthand for `with` and `ty`, with the same restrictions
 //!
 //! ## Examples
 //!
@@ -226,6 +500,108 @@
 //! }
 //! ```
 //!
+//! ### Recomposing
+//!
+//! ```rust
+//! use dissolve_derive::Dissolve;
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(recompose)]
+//! struct Session {
+//!     #[dissolved(rename = "user")]
+//!     user_id: u64,
+//!
+//!     #[dissolved(skip)]
+//!     token: String,
+//! }
+//!
+//! let session = Session { user_id: 7, token: "secret".to_string() };
+//!
+//! let mut parts = session.dissolve();
+//! parts.user += 1;
+//!
+//! let session = parts.into_original("rotated".to_string());
+//! ```
+//!
+//! Skipped fields marked `keep_for_rebuild` do not have to be supplied again. Their values travel
+//! in an opaque carrier instead:
+//!
+//! ```rust
+//! use dissolve_derive::Dissolve;
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(recompose)]
+//! struct Session {
+//!     user_id: u64,
+//!
+//!     #[dissolved(skip, keep_for_rebuild)]
+//!     token: String,
+//! }
+//!
+//! let session = Session { user_id: 7, token: "secret".to_string() };
+//!
+//! let (mut parts, carrier) = session.dissolve();
+//! parts.user_id += 1;
+//!
+//! let session = parts.into_original(carrier);
+//! ```
+//!
+//! ### Nesting Fields
+//!
+//! ```rust
+//! use dissolve_derive::Dissolve;
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(nested)]
+//! struct Customer {
+//!     name: String,
+//!
+//!     #[dissolved(group = "address")]
+//!     street: String,
+//!
+//!     #[dissolved(group = "address")]
+//!     city: String,
+//! }
+//!
+//! let customer = Customer {
+//!     name: "Ada".to_string(),
+//!     street: "Main Street".to_string(),
+//!     city: "London".to_string(),
+//! };
+//!
+//! let CustomerDissolved { name, address } = customer.dissolve();
+//! let CustomerAddressDissolved { street, city } = address;
+//! ```
+//!
+//! ### Dissolving Types With `Drop`
+//!
+//! Fields cannot be moved out of a struct that implements `Drop`. With `manually_drop`, the
+//! struct's `Drop` impl is bypassed instead, and the caller decides what happens to the fields:
+//!
+//! ```rust
+//! use std::mem::ManuallyDrop;
+//!
+//! use dissolve_derive::Dissolve;
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(manually_drop)]
+//! struct TempFile {
+//!     path: String,
+//! }
+//!
+//! impl Drop for TempFile {
+//!     fn drop(&mut self) {
+//!         // remove the file from disk
+//!     }
+//! }
+//!
+//! let file = TempFile { path: "/tmp/output".to_string() };
+//!
+//! // The file is kept, its path is handed over to the caller
+//! let TempFileDissolved { path } = file.dissolve();
+//! let path = ManuallyDrop::into_inner(path);
+//! ```
+//!
 //! ### Tuple Structs
 //!
 //! ```rust
@@ -237,353 +613,4539 @@
 //! let coord = Coordinate(1.0, 2.0, "label".to_string());
 //! let (x, y) = coord.dissolve();
 //! ```
+//!
+//! Skipped fields shift the positions of the fields after them. When fields are skipped, the docs
+//! of the generated `dissolve` method spell out the mapping, e.g. "Returns `(self.0, self.1)`,
+//! skipping `self.2`."
+//!
+//! With `output = "struct"`, the fields keep their index in their name instead:
+//!
+//! ```rust
+//! use dissolve_derive::Dissolve;
+//!
+//! #[derive(Dissolve)]
+//! #[dissolve(output = "struct")]
+//! struct Coordinate(f64, #[dissolved(skip)] String, f64);
+//!
+//! let coord = Coordinate(1.0, "label".to_string(), 2.0);
+//! let CoordinateDissolved { field_0, field_2 } = coord.dissolve();
+//! ```
+//!
+//! ### Enums
+//!
+//! Each variant keeps its shape in the generated `{EnumName}Dissolved` enum. Fields of struct
+//! variants can be skipped or renamed, fields of tuple variants can be skipped:
+//!
+//! ```rust
+//! use dissolve_derive::Dissolve;
+//!
+//! #[derive(Dissolve)]
+//! enum Job {
+//!     Queued,
+//!     Running {
+//!         #[dissolved(rename = "pid")]
+//!         process_id: u32,
+//!
+//!         #[dissolved(skip)]
+//!         started_at: std::time::Instant,
+//!     },
+//!     Failed(String, #[dissolved(skip)] u32),
+//! }
+//!
+//! let job = Job::Running { process_id: 42, started_at: std::time::Instant::now() };
+//!
+//! match job.dissolve() {
+//!     JobDissolved::Queued => {},
+//!     JobDissolved::Running { pid } => assert_eq!(pid, 42),
+//!     JobDissolved::Failed(reason) => panic!("{reason}"),
+//! }
+//! ```
+//!
+//! Container options that only make sense for structs, such as `recompose` or `snapshot`, are
+//! rejected for enums.
+//!
+//! An enum cannot hold a marker field, so generic parameters that only appear in skipped fields are
+//! left off the dissolved enum: skipping the `T` of `Job<T> { Ready { input: T, id: u32 } }` turns
+//! it into a plain `JobDissolved`.
+//!
+//! ### Foreign Types
+//!
+//! Types defined in other crates cannot derive `Dissolve`. [`dissolve_for!`] takes a mirror of
+//! their definition instead, and generates a free `dissolve_{type_name}` function in place of the
+//! `dissolve` method.
+//!
+//! ### Attribute Form
+//!
+//! [`macro@dissolve`] is an attribute macro generating the same code as the derive, taking the
+//! container options as its arguments: `#[dissolve_derive::dissolve(map)]`.
+
+use std::collections::{HashMap, HashSet};
 
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
+use quote::{ToTokens, format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
 use syn::{
 	Data, DeriveInput, Error, Expr, ExprLit, Field, Fields, FieldsUnnamed, Index, Lit, Meta,
-	MetaNameValue, Result, parse_macro_input,
+	MetaNameValue, Result,
 };
 
-/// Derive macro that generates a `dissolve(self)` method for structs.
+/// Derive macro that generates a `dissolve(self)` method for structs and enums.
 ///
 /// For named structs, returns a struct with public fields named `{OriginalName}Dissolved`.
 /// For tuple structs, returns a tuple with the included fields.
+/// For enums, returns an enum named `{OriginalName}Dissolved` with the same variants, each holding
+/// the included fields of the original variant.
+///
+/// The generated method takes `self` by value, so a struct can be dissolved at most once: any
+/// later use of it, including a second call to `dissolve`, is rejected by the borrow checker.
+///
+/// Everything generated is a type, an impl, a function or a `const _` item, never a module or a
+/// macro, so the derive works the same on types declared inside a function body.
 ///
 /// # Attributes
 ///
-/// - `#[dissolved(skip)]` - Skip this field in the dissolved struct/tuple
-/// - `#[dissolved(rename = "new_name")]` - Rename this field in the dissolved struct
+/// `#[dissolve(...)]` on the type and `#[dissolved(...)]` on its fields take the options listed in
+/// the [crate documentation](crate#attributes).
 #[proc_macro_derive(Dissolve, attributes(dissolve, dissolved))]
 pub fn derive_dissolve(input: TokenStream) -> TokenStream {
-	let input = parse_macro_input!(input as DeriveInput);
+	let input = match parse_derive_input(input.into()) {
+		Ok(input) => input,
+		Err(err) => return err.to_compile_error().into(),
+	};
 
-	match generate_dissolve_impl(&input) {
+	match generate_dissolve_impl(&input, false) {
 		Ok(tokens) => tokens.into(),
 		Err(err) => err.to_compile_error().into(),
 	}
 }
 
-#[derive(Debug, Clone)]
-struct ContainerAttributes {
-	visibility: syn::Visibility,
-}
-
-impl ContainerAttributes {
-	const IDENT: &str = "dissolve";
-
-	const VISIBILITY_IDENT: &str = "visibility";
-
-	fn from_derive_input(input: &DeriveInput) -> Result<Self> {
-		let mut visibility = syn::parse_str::<syn::Visibility>("pub").unwrap();
-
-		for attr in input.attrs.iter().filter(|attr| attr.path().is_ident(Self::IDENT)) {
-			match &attr.meta {
-				Meta::List(_) => {
-					let nested_metas = attr.parse_args_with(
-						syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
-					)?;
-
-					for nested_meta in nested_metas {
-						match &nested_meta {
-							Meta::NameValue(MetaNameValue { path, value, .. }) => {
-								if path.is_ident(Self::VISIBILITY_IDENT) {
-									match value {
-										Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => {
-											let vis_str = lit_str.value();
-											visibility = syn::parse_str::<syn::Visibility>(&vis_str)
-												.map_err(|e| {
-													Error::new_spanned(
-														value,
-														format!(
-															"invalid visibility: {e}. Supported: 'pub', 'pub(crate)', 'pub(super)', 'pub(self)' or empty for private",
-														),
-													)
-												})?;
-										},
-										_ => {
-											return Err(Error::new_spanned(
-												value,
-												"visibility value must be a string literal",
-											));
-										},
-									}
-								} else {
-									return Err(Error::new_spanned(
-										path,
-										format!(
-											"unknown dissolve attribute option '{}'; supported option: {}",
-											path.get_ident()
-												.map(|i| i.to_string())
-												.unwrap_or_default(),
-											Self::VISIBILITY_IDENT,
-										),
-									));
-								}
-							},
-							_ => {
-								return Err(Error::new_spanned(
-									nested_meta,
-									"dissolve container attribute must use name-value syntax: #[dissolve(visibility = \"...\")]",
-								));
-							},
-						}
-					}
-				},
-				_ => {
-					return Err(Error::new_spanned(
-						attr,
-						"dissolve attribute must use list syntax: #[dissolve(visibility = \"...\")]",
-					));
-				},
-			}
-		}
+/// Function-like macro that generates the dissolved type of a type defined in another crate.
+///
+/// `#[derive(Dissolve)]` cannot be added to a foreign type, so this macro takes a mirror of its
+/// definition instead, with the same `#[dissolve(...)]` and `#[dissolved(...)]` attributes as the
+/// derive. The mirror must be named like the foreign type, which has to be in scope, and list its
+/// fields with their types.
+///
+/// Inherent methods cannot be added to a foreign type either, so `dissolve` is generated as a free
+/// function named after the type, e.g. `dissolve_point(point: Point)` for `Point`. It moves the
+/// fields out of the value, so they must be accessible from where the macro is invoked.
+///
+/// ```rust
+/// use std::ops::Range;
+///
+/// use dissolve_derive::dissolve_for;
+///
+/// dissolve_for! {
+///     struct Range<Idx> {
+///         start: Idx,
+///         end: Idx,
+///     }
+/// }
+///
+/// let RangeDissolved { start, end } = dissolve_range(2..5);
+/// assert_eq!((start, end), (2, 5));
+/// ```
+///
+/// The `map` option is not supported, since `map_dissolved` would be a method of the foreign type.
+/// For the same reason, `newtype` does not implement `From`.
+#[proc_macro]
+pub fn dissolve_for(input: TokenStream) -> TokenStream {
+	let input = match parse_derive_input(input.into()) {
+		Ok(input) => input,
+		Err(err) => return err.to_compile_error().into(),
+	};
 
-		Ok(Self { visibility })
+	match generate_dissolve_impl(&input, true) {
+		Ok(tokens) => tokens.into(),
+		Err(err) => err.to_compile_error().into(),
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum DissolvedOption {
-	Skip,
-	Rename(syn::Ident),
-}
+/// Attribute macro form of `#[derive(Dissolve)]`.
+///
+/// Its arguments are container options, as in `#[dissolve(...)]`, and the item takes the same
+/// `#[dissolve(...)]` and `#[dissolved(...)]` attributes as with the derive. The item is emitted
+/// without these attributes, followed by the generated code.
+///
+/// Unlike the derive, the attribute macro owns the item, which leaves room for options that
+/// rewrite it. The derive remains the common case.
+///
+/// ```rust
+/// #[dissolve_derive::dissolve(visibility = "pub(crate)")]
+/// struct Credentials {
+///     user: String,
+///
+///     #[dissolved(skip)]
+///     password: String,
+/// }
+///
+/// let credentials = Credentials { user: "admin".into(), password: "hunter2".into() };
+/// assert_eq!(credentials.dissolve().user, "admin");
+/// ```
+///
+/// Refer to it by path: importing it as `dissolve` makes `#[dissolve(...)]` ambiguous with the
+/// helper attribute of the derive.
+#[proc_macro_attribute]
+pub fn dissolve(args: TokenStream, item: TokenStream) -> TokenStream {
+	let item = proc_macro2::TokenStream::from(item);
+	let args = proc_macro2::TokenStream::from(args);
 
-#[derive(Debug, Clone)]
-struct FieldInfo {
-	should_skip: bool,
-	renamed_to: Option<syn::Ident>,
-}
+	let generated = parse_derive_input(item.clone()).and_then(|mut input| {
+		if !args.is_empty() {
+			input.attrs.insert(0, syn::parse_quote!(#[dissolve(#args)]));
+		}
 
-impl DissolvedOption {
-	const IDENT: &str = "dissolved";
+		generate_dissolve_impl(&input, false)
+	});
+	let generated = generated.unwrap_or_else(|err| err.to_compile_error());
 
-	const SKIP_IDENT: &str = "skip";
+	// Without the derive, nothing declares the helper attributes, so they must not be emitted
+	let item = strip_dissolve_attributes(item);
 
-	const RENAME_IDENT: &str = "rename";
+	quote! {
+		#item
+		#generated
+	}
+	.into()
+}
 
-	fn from_meta(meta: &Meta) -> Result<Self> {
-		let unknown_attribute_err = |path: &syn::Path| {
-			let path_str = path
-				.segments
-				.iter()
-				.map(|seg| seg.ident.to_string())
-				.collect::<Vec<_>>()
-				.join("::");
+/// Removes the `#[dissolve(...)]` and `#[dissolved(...)]` attributes from an item, leaving its
+/// other tokens, including default field values, as they are.
+fn strip_dissolve_attributes(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+	use proc_macro2::{Delimiter, Group, TokenTree};
 
-			Error::new_spanned(
-				path,
-				format!(
-					"unknown dissolved attribute option '{}'; supported options: {}, {} = \"new_name\"",
-					Self::SKIP_IDENT,
-					Self::RENAME_IDENT,
-					path_str,
-				),
+	let is_dissolve_attribute = |token: Option<&TokenTree>| match token {
+		Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+			matches!(
+				group.stream().into_iter().next(),
+				Some(TokenTree::Ident(ident))
+					if ident == ContainerAttributes::IDENT || ident == DissolvedOption::IDENT
 			)
-		};
-
-		let opt = match meta {
-			Meta::Path(path) => {
-				if !path.is_ident(Self::SKIP_IDENT) {
-					return Err(unknown_attribute_err(path));
-				}
+		},
+		_ => false,
+	};
 
-				DissolvedOption::Skip
-			},
-			Meta::NameValue(MetaNameValue { path, value, .. }) => {
-				if !path.is_ident(Self::RENAME_IDENT) {
-					return Err(unknown_attribute_err(path));
-				}
+	let mut stripped = Vec::new();
+	let mut tokens = tokens.into_iter().peekable();
 
-				match value {
-					Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => {
-						syn::parse_str::<syn::Ident>(&lit_str.value())
-							.map(DissolvedOption::Rename)?
-					},
-					_ => {
-						return Err(Error::new_spanned(
-							value,
-							format!("{} value must be a string literal", Self::RENAME_IDENT),
-						));
-					},
-				}
+	while let Some(token) = tokens.next() {
+		match token {
+			TokenTree::Punct(punct)
+				if punct.as_char() == '#' && is_dissolve_attribute(tokens.peek()) =>
+			{
+				tokens.next();
 			},
-			Meta::List(_) => {
-				return Err(Error::new_spanned(
-					meta,
-					"nested lists are not supported in dissolved attributes",
-				));
+			TokenTree::Group(group) => {
+				let mut new_group =
+					Group::new(group.delimiter(), strip_dissolve_attributes(group.stream()));
+				new_group.set_span(group.span());
+				stripped.push(TokenTree::Group(new_group));
 			},
-		};
-
-		Ok(opt)
+			token => stripped.push(token),
+		}
 	}
+
+	stripped.into_iter().collect()
 }
 
-impl FieldInfo {
-	fn new() -> Self {
-		Self { should_skip: false, renamed_to: None }
-	}
+fn parse_derive_input(input: proc_macro2::TokenStream) -> Result<DeriveInput> {
+	syn::parse2(input.clone()).or_else(|err| {
+		// `syn` 2, up to at least 2.0.119, cannot parse default field values (`field: Type = expr`),
+		// so retry without them. They are irrelevant here anyway, since dissolving moves out of an
+		// existing value.
+		syn::parse2(strip_default_field_values(input)).map_err(|_| err)
+	})
 }
 
-fn generate_dissolve_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
-	let struct_name = &input.ident;
-	let generics = &input.generics;
-	let container_attrs = ContainerAttributes::from_derive_input(input)?;
+/// Removes default values from the named fields of a struct definition.
+///
+/// The fields are delimited by the trailing brace group of the item. Within a field, the first
+/// `=` outside of angle brackets starts the default value, which runs up to the next `,` that is
+/// not part of a turbofish or a qualified path.
+///
+/// This is a stopgap for syn 2.0.119, which has no `Field::default`: switch to the parsing of syn
+/// once the `syn` version this crate depends on supports default field values, as syn 3 does.
+fn strip_default_field_values(input: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+	use proc_macro2::{Delimiter, Group, TokenTree};
 
-	let Data::Struct(data_struct) = &input.data else {
-		return Err(Error::new_spanned(
-			input,
-			"Dissolve can only be derived for structs",
-		));
+	let mut tokens: Vec<_> = input.into_iter().collect();
+
+	let Some(TokenTree::Group(body)) = tokens.last() else {
+		return tokens.into_iter().collect();
 	};
 
-	match &data_struct.fields {
-		Fields::Named(fields) => {
-			generate_named_struct_impl(struct_name, generics, fields, &container_attrs)
-		},
-		Fields::Unnamed(fields) => {
-			generate_tuple_struct_impl(struct_name, generics, fields, &container_attrs)
-		},
-		Fields::Unit => Err(Error::new_spanned(
-			input,
-			"Dissolve cannot be derived for unit structs",
-		)),
+	if body.delimiter() != Delimiter::Brace {
+		return tokens.into_iter().collect();
 	}
-}
 
-fn generate_named_struct_impl(
-	struct_name: &syn::Ident,
-	generics: &syn::Generics,
-	fields: &syn::FieldsNamed,
-	container_attrs: &ContainerAttributes,
-) -> Result<proc_macro2::TokenStream> {
-	let included_fields: Vec<_> = fields
-		.named
-		.iter()
-		.map(|field| {
-			let info = get_field_info(field)?;
-			if info.should_skip {
-				Ok((None, info))
-			} else {
-				Ok((Some(field), info))
-			}
-		})
-		.filter_map(|res| match res {
-			Ok((Some(field), info)) => Some(Ok((field, info))),
-			Err(e) => Some(Err(e)),
-			_ => None,
-		})
-		.collect::<Result<_>>()?;
+	let mut stripped = Vec::new();
+	let mut in_default = false;
+	let mut angle_depth = 0usize;
+	let mut previous: Option<TokenTree> = None;
 
-	if included_fields.is_empty() {
-		return Err(Error::new_spanned(
-			struct_name,
-			"cannot create dissolved struct with no fields (all fields are skipped)",
-		));
-	}
+	for token in body.stream() {
+		if let TokenTree::Punct(punct) = &token {
+			let previous_punct = match &previous {
+				Some(TokenTree::Punct(previous)) => Some(previous),
+				_ => None,
+			};
 
-	let field_definitions = included_fields.iter().map(|(field, info)| {
-		// unwrap is safe because struct has named fields
-		let original_name = field.ident.as_ref().unwrap();
-		let ty = &field.ty;
+			match punct.as_char() {
+				',' if angle_depth == 0 => {
+					in_default = false;
+				},
+				'=' if angle_depth == 0 && !in_default => {
+					let is_operator = previous_punct
+						.is_some_and(|p| p.spacing() == proc_macro2::Spacing::Joint)
+						|| punct.spacing() == proc_macro2::Spacing::Joint;
 
-		let dissolved_field_name = match &info.renamed_to {
-			Some(new_name) => new_name,
-			None => original_name,
-		};
+					if !is_operator {
+						in_default = true;
+						previous = Some(token);
+						continue;
+					}
+				},
+				'<' => {
+					// In a default value, only turbofishes and qualified paths open angle brackets,
+					// any other `<` is a comparison, unless it is nested in one of them, like the
+					// `<` of `Vec<u8>` in `BTreeMap::<Vec<u8>, u8>::new()`
+					let opens_generics = !in_default
+						|| angle_depth > 0 || previous_punct
+						.is_some_and(|p| matches!(p.as_char(), ':' | '='));
 
-		// Extract doc comments from the original field
-		let doc_attrs = field.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+					if opens_generics {
+						angle_depth += 1;
+					}
+				},
+				// `->` in function pointer types does not close an angle bracket
+				'>' if angle_depth > 0 && previous_punct.is_none_or(|p| p.as_char() != '-') => {
+					angle_depth -= 1;
+				},
+				_ => {},
+			}
+		}
 
-		quote! {
-			#(#doc_attrs)*
-			pub #dissolved_field_name: #ty
+		if !in_default {
+			stripped.push(token.clone());
 		}
-	});
 
-	let field_moves = included_fields.iter().map(|(field, info)| {
-		// unwrap is safe because struct has named fields
-		let original_name = field.ident.as_ref().unwrap();
+		previous = Some(token);
+	}
 
-		let dissolved_field_name = match &info.renamed_to {
-			Some(new_name) => new_name,
-			None => original_name,
-		};
+	let mut new_body = Group::new(Delimiter::Brace, stripped.into_iter().collect());
+	new_body.set_span(body.span());
+	*tokens.last_mut().unwrap() = TokenTree::Group(new_body);
 
-		quote! { #dissolved_field_name: self.#original_name }
-	});
+	tokens.into_iter().collect()
+}
 
-	let dissolved_struct_name = format_ident!("{}Dissolved", struct_name);
+#[derive(Debug, Clone)]
+struct ContainerAttributes {
+	visibility: syn::Visibility,
+	recompose: bool,
+	into_tuple: bool,
+	from_dissolved: bool,
+	doc_provenance: bool,
+	map: bool,
+	newtype: bool,
+	array: bool,
+	prune_generics: bool,
+	inherit_field_vis: bool,
+	test_helpers: bool,
+	manually_drop: bool,
+	move_strategy: MoveStrategy,
+	move_strategy_span: Option<proc_macro2::Span>,
+	tuple_output: TupleOutput,
+	tuple_output_span: Option<proc_macro2::Span>,
+	rename_all: Option<(Vec<RenameTransform>, syn::LitStr)>,
+	only: Option<Vec<syn::LitStr>>,
+	only_public: Option<Meta>,
+	keep_attrs: Option<Vec<syn::Path>>,
+	snapshot: Option<syn::Ident>,
+	boxed: bool,
+	transparent: bool,
+	as_ref: bool,
+	derives: Option<Vec<syn::Path>>,
+	method_attrs: Vec<Meta>,
+	field_extractors: bool,
+	debug_generated: bool,
+	nested: bool,
+	opaque: bool,
+	after: Option<syn::Path>,
+	dynamic: bool,
+	tagged: bool,
+	tuple_from: bool,
+	transition_to: Option<syn::Path>,
+	seal: bool,
+	drain: bool,
+	track_caller: bool,
+	via: Option<Vec<syn::Ident>>,
+	serde: bool,
+	cfg: Option<Meta>,
+	boxed_self: bool,
+	from_arc: bool,
+	from_rc: bool,
+	copy_view: bool,
+	finalize: Option<syn::Path>,
+	no_auto_doc: bool,
+	to_json: bool,
+	assert_size: bool,
+	terminal: Option<Meta>,
+	method: Option<syn::Ident>,
+	/// Set by `dissolve_for!` rather than by an option: the type is defined in another crate, so
+	/// `dissolve` is generated as a free function instead of an inherent method.
+	remote: bool,
+}
 
-	// Split generics for use in different positions
-	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+/// How the `dissolve` body moves the included fields out of `self`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MoveStrategy {
+	/// Move each field with a separate `self.field` expression.
+	Field,
+	/// Destructure `self` once with a `let Self { .. } = self;` pattern.
+	Destructure,
+}
 
-	let dissolved_struct_doc = format!(
-		"Dissolved struct for [`{struct_name}`].\n\n\
-		This struct contains all non-skipped fields from the original struct. \
-		The visibility of this struct matches the visibility of the `dissolve` method. \
-		Fields may be renamed according to `#[dissolved(rename = \"...\")]` attributes.",
-	);
+impl MoveStrategy {
+	const FIELD: &str = "field";
+
+	const DESTRUCTURE: &str = "destructure";
+}
+
+/// What a tuple struct dissolves into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TupleOutput {
+	/// A tuple of the included fields.
+	Tuple,
+	/// A named struct with a `field_{index}` field per included field.
+	Struct,
+}
+
+impl TupleOutput {
+	const TUPLE: &str = "tuple";
+
+	const STRUCT: &str = "struct";
+}
+
+impl ContainerAttributes {
+	const IDENT: &str = "dissolve";
+
+	const VISIBILITY_IDENT: &str = "visibility";
+
+	const VISIBILITY_INHERIT: &str = "inherit";
+
+	const RECOMPOSE_IDENT: &str = "recompose";
+
+	const INTO_TUPLE_IDENT: &str = "into_tuple";
+
+	const FROM_DISSOLVED_IDENT: &str = "from_dissolved";
+
+	const DOC_PROVENANCE_IDENT: &str = "doc_provenance";
+
+	const MAP_IDENT: &str = "map";
+
+	const NEWTYPE_IDENT: &str = "newtype";
+
+	const ARRAY_IDENT: &str = "array";
+
+	const PRUNE_GENERICS_IDENT: &str = "prune_generics";
+
+	const INHERIT_FIELD_VIS_IDENT: &str = "inherit_field_vis";
+
+	const FIELDS_VISIBILITY_IDENT: &str = "fields_visibility";
+
+	const FIELDS_VISIBILITY_PUB: &str = "pub";
+
+	const FIELDS_VISIBILITY_MIRROR: &str = "mirror";
+
+	const SERDE_IDENT: &str = "serde";
+
+	const CFG_IDENT: &str = "cfg";
+
+	const BOXED_SELF_IDENT: &str = "boxed_self";
+
+	const FROM_ARC_IDENT: &str = "from_arc";
+
+	const FROM_RC_IDENT: &str = "from_rc";
+
+	const COPY_VIEW_IDENT: &str = "copy_view";
+
+	const FINALIZE_IDENT: &str = "finalize";
+
+	const NO_AUTO_DOC_IDENT: &str = "no_auto_doc";
+
+	const TO_JSON_IDENT: &str = "to_json";
+
+	const TERMINAL_IDENT: &str = "terminal";
+
+	const ASSERT_SIZE_IDENT: &str = "assert_size";
+
+	const METHOD_IDENT: &str = "method";
+
+	const METHOD_DEFAULT: &str = "dissolve";
+
+	const TEST_HELPERS_IDENT: &str = "test_helpers";
+
+	const MANUALLY_DROP_IDENT: &str = "manually_drop";
+
+	const MOVE_STRATEGY_IDENT: &str = "move_strategy";
+
+	const OUTPUT_IDENT: &str = "output";
+
+	const RENAME_ALL_IDENT: &str = "rename_all";
+
+	const ONLY_IDENT: &str = "only";
+
+	const ONLY_PUBLIC_IDENT: &str = "only_public";
+
+	const KEEP_ATTRS_IDENT: &str = "keep_attrs";
+
+	const SNAPSHOT_IDENT: &str = "snapshot";
+
+	const BOXED_IDENT: &str = "boxed";
+
+	const TRANSPARENT_IDENT: &str = "transparent";
+
+	const AS_REF_IDENT: &str = "as_ref";
+
+	const DERIVE_IDENT: &str = "derive";
+
+	const METHOD_ATTRS_IDENT: &str = "method_attrs";
+
+	const FIELD_EXTRACTORS_IDENT: &str = "field_extractors";
+
+	const DEBUG_GENERATED_IDENT: &str = "debug_generated";
+
+	const NESTED_IDENT: &str = "nested";
+
+	const OPAQUE_IDENT: &str = "opaque";
+
+	const AFTER_IDENT: &str = "after";
+
+	const DYNAMIC_IDENT: &str = "dynamic";
+
+	const TAGGED_IDENT: &str = "tagged";
+
+	const TUPLE_FROM_IDENT: &str = "tuple_from";
+
+	const TRANSITION_TO_IDENT: &str = "transition_to";
+
+	const VIA_IDENT: &str = "via";
+
+	const SEAL_IDENT: &str = "seal";
+
+	const DRAIN_IDENT: &str = "drain";
+
+	const TRACK_CALLER_IDENT: &str = "track_caller";
+
+	const OPTIONS: &[&str] = &[
+		Self::VISIBILITY_IDENT,
+		Self::RECOMPOSE_IDENT,
+		Self::INTO_TUPLE_IDENT,
+		Self::FROM_DISSOLVED_IDENT,
+		Self::DOC_PROVENANCE_IDENT,
+		Self::MAP_IDENT,
+		Self::NEWTYPE_IDENT,
+		Self::ARRAY_IDENT,
+		Self::PRUNE_GENERICS_IDENT,
+		Self::INHERIT_FIELD_VIS_IDENT,
+		Self::TEST_HELPERS_IDENT,
+		Self::MANUALLY_DROP_IDENT,
+		Self::MOVE_STRATEGY_IDENT,
+		Self::OUTPUT_IDENT,
+		Self::RENAME_ALL_IDENT,
+		Self::ONLY_IDENT,
+		Self::ONLY_PUBLIC_IDENT,
+		Self::KEEP_ATTRS_IDENT,
+		Self::SNAPSHOT_IDENT,
+		Self::BOXED_IDENT,
+		Self::TRANSPARENT_IDENT,
+		Self::AS_REF_IDENT,
+		Self::DERIVE_IDENT,
+		Self::METHOD_ATTRS_IDENT,
+		Self::FIELD_EXTRACTORS_IDENT,
+		Self::DEBUG_GENERATED_IDENT,
+		Self::NESTED_IDENT,
+		Self::OPAQUE_IDENT,
+		Self::AFTER_IDENT,
+		Self::DYNAMIC_IDENT,
+		Self::TAGGED_IDENT,
+		Self::TUPLE_FROM_IDENT,
+		Self::FIELDS_VISIBILITY_IDENT,
+		Self::SERDE_IDENT,
+		Self::CFG_IDENT,
+		Self::BOXED_SELF_IDENT,
+		Self::FROM_ARC_IDENT,
+		Self::FROM_RC_IDENT,
+		Self::COPY_VIEW_IDENT,
+		Self::FINALIZE_IDENT,
+		Self::NO_AUTO_DOC_IDENT,
+		Self::TO_JSON_IDENT,
+		Self::TERMINAL_IDENT,
+		Self::METHOD_IDENT,
+		Self::ASSERT_SIZE_IDENT,
+		Self::TRANSITION_TO_IDENT,
+		Self::VIA_IDENT,
+		Self::SEAL_IDENT,
+		Self::DRAIN_IDENT,
+		Self::TRACK_CALLER_IDENT,
+	];
+
+	fn from_derive_input(input: &DeriveInput) -> Result<Self> {
+		let mut container_attrs = Self {
+			visibility: syn::parse_str::<syn::Visibility>("pub").unwrap(),
+			recompose: false,
+			into_tuple: false,
+			from_dissolved: false,
+			doc_provenance: false,
+			map: false,
+			newtype: false,
+			array: false,
+			prune_generics: false,
+			inherit_field_vis: false,
+			test_helpers: false,
+			manually_drop: false,
+			move_strategy: MoveStrategy::Field,
+			move_strategy_span: None,
+			tuple_output: TupleOutput::Tuple,
+			tuple_output_span: None,
+			rename_all: None,
+			only: None,
+			only_public: None,
+			keep_attrs: None,
+			snapshot: None,
+			boxed: false,
+			transparent: false,
+			as_ref: false,
+			derives: None,
+			method_attrs: Vec::new(),
+			field_extractors: false,
+			debug_generated: false,
+			nested: false,
+			opaque: false,
+			after: None,
+			dynamic: false,
+			tagged: false,
+			tuple_from: false,
+			transition_to: None,
+			seal: false,
+			drain: false,
+			track_caller: false,
+			via: None,
+			serde: false,
+			cfg: None,
+			boxed_self: false,
+			from_arc: false,
+			from_rc: false,
+			copy_view: false,
+			finalize: None,
+			no_auto_doc: false,
+			to_json: false,
+			assert_size: false,
+			terminal: None,
+			method: None,
+			remote: false,
+		};
+
+		let mut option_count = 0;
+
+		for attr in input.attrs.iter().filter(|attr| attr.path().is_ident(Self::IDENT)) {
+			match &attr.meta {
+				Meta::List(_) => {
+					let nested_metas = attr.parse_args_with(
+						syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+					)?;
+
+					for nested_meta in nested_metas {
+						container_attrs.parse_option(&nested_meta, &input.vis)?;
+						option_count += 1;
+					}
+				},
+				// A bare `#[dissolve]` is most likely an unfinished attribute rather than a request for
+				// the defaults, which apply without any attribute
+				Meta::Path(_) => {
+					return Err(Error::new_spanned(
+						attr,
+						"dissolve attribute requires options, e.g. #[dissolve(visibility = \"...\")]; \
+						the attribute is optional, remove it to use the defaults",
+					));
+				},
+				Meta::NameValue(_) => {
+					return Err(Error::new_spanned(
+						attr,
+						"dissolve attribute must use list syntax: #[dissolve(visibility = \"...\")]",
+					));
+				},
+			}
+		}
+
+		if let Some(meta) = container_attrs.terminal.as_ref().filter(|_| option_count > 1) {
+			return Err(Error::new_spanned(
+				meta,
+				format!(
+					"{} cannot be combined with other options, as nothing is generated for a \
+					terminal type",
+					Self::TERMINAL_IDENT,
+				),
+			));
+		}
+
+		container_attrs.validate()?;
+
+		Ok(container_attrs)
+	}
+
+	/// Applies a single option, where `input_vis` is the visibility of the type being derived.
+	fn parse_option(&mut self, meta: &Meta, input_vis: &syn::Visibility) -> Result<()> {
+		let path = meta.path();
+
+		if path.is_ident(Self::VISIBILITY_IDENT) {
+			let lit_str = expect_str(meta)?;
+
+			if lit_str.value() == Self::VISIBILITY_INHERIT {
+				self.visibility = input_vis.clone();
+				return Ok(());
+			}
+
+			self.visibility = syn::parse_str::<syn::Visibility>(&lit_str.value()).map_err(|e| {
+				Error::new_spanned(
+					lit_str,
+					format!(
+						"invalid visibility: {e}. Supported: 'pub', 'pub(crate)', 'pub(super)', 'pub(self)', 'pub(in path)', '{}' or empty for private",
+						Self::VISIBILITY_INHERIT,
+					),
+				)
+			})?;
+		} else if path.is_ident(Self::RECOMPOSE_IDENT) {
+			expect_flag(meta)?;
+			self.recompose = true;
+		} else if path.is_ident(Self::INTO_TUPLE_IDENT) {
+			expect_flag(meta)?;
+			self.into_tuple = true;
+		} else if path.is_ident(Self::FROM_DISSOLVED_IDENT) {
+			expect_flag(meta)?;
+			self.from_dissolved = true;
+		} else if path.is_ident(Self::DOC_PROVENANCE_IDENT) {
+			expect_flag(meta)?;
+			self.doc_provenance = true;
+		} else if path.is_ident(Self::MAP_IDENT) {
+			expect_flag(meta)?;
+			self.map = true;
+		} else if path.is_ident(Self::NEWTYPE_IDENT) {
+			expect_flag(meta)?;
+			self.newtype = true;
+		} else if path.is_ident(Self::ARRAY_IDENT) {
+			expect_flag(meta)?;
+			self.array = true;
+		} else if path.is_ident(Self::PRUNE_GENERICS_IDENT) {
+			expect_flag(meta)?;
+			self.prune_generics = true;
+		} else if path.is_ident(Self::INHERIT_FIELD_VIS_IDENT) {
+			expect_flag(meta)?;
+			self.inherit_field_vis = true;
+		} else if path.is_ident(Self::FIELDS_VISIBILITY_IDENT) {
+			// `mirror` is the policy of `inherit_field_vis`, spelled as a value
+			let lit_str = expect_str(meta)?;
+			self.inherit_field_vis = match lit_str.value().as_str() {
+				Self::FIELDS_VISIBILITY_PUB => false,
+				Self::FIELDS_VISIBILITY_MIRROR => true,
+				other => {
+					return Err(Error::new_spanned(
+						lit_str,
+						format!(
+							"unknown {} '{other}'; supported values: {}, {}",
+							Self::FIELDS_VISIBILITY_IDENT,
+							Self::FIELDS_VISIBILITY_MIRROR,
+							Self::FIELDS_VISIBILITY_PUB,
+						),
+					));
+				},
+			};
+		} else if path.is_ident(Self::TEST_HELPERS_IDENT) {
+			expect_flag(meta)?;
+			self.test_helpers = true;
+		} else if path.is_ident(Self::MANUALLY_DROP_IDENT) {
+			expect_flag(meta)?;
+			self.manually_drop = true;
+		} else if path.is_ident(Self::MOVE_STRATEGY_IDENT) {
+			let lit_str = expect_str(meta)?;
+			self.move_strategy = match lit_str.value().as_str() {
+				MoveStrategy::FIELD => MoveStrategy::Field,
+				MoveStrategy::DESTRUCTURE => MoveStrategy::Destructure,
+				other => {
+					return Err(Error::new_spanned(
+						lit_str,
+						format!(
+							"unknown {} '{other}'; supported values: {}, {}",
+							Self::MOVE_STRATEGY_IDENT,
+							MoveStrategy::DESTRUCTURE,
+							MoveStrategy::FIELD,
+						),
+					));
+				},
+			};
+			self.move_strategy_span = Some(lit_str.span());
+		} else if path.is_ident(Self::OUTPUT_IDENT) {
+			let lit_str = expect_str(meta)?;
+			self.tuple_output = match lit_str.value().as_str() {
+				TupleOutput::TUPLE => TupleOutput::Tuple,
+				TupleOutput::STRUCT => TupleOutput::Struct,
+				other => {
+					return Err(Error::new_spanned(
+						lit_str,
+						format!(
+							"unknown {} '{other}'; supported values: {}, {}",
+							Self::OUTPUT_IDENT,
+							TupleOutput::STRUCT,
+							TupleOutput::TUPLE,
+						),
+					));
+				},
+			};
+			self.tuple_output_span = Some(lit_str.span());
+		} else if path.is_ident(Self::RENAME_ALL_IDENT) {
+			let lit_str = expect_str(meta)?;
+			let transforms = RenameTransform::parse_all(lit_str, Self::RENAME_ALL_IDENT)?;
+			self.rename_all = Some((transforms, lit_str.clone()));
+		} else if path.is_ident(Self::ONLY_IDENT) {
+			let names = expect_list::<syn::LitStr>(meta)?;
+			self.only.get_or_insert_with(Vec::new).extend(names);
+		} else if path.is_ident(Self::ONLY_PUBLIC_IDENT) {
+			expect_flag(meta)?;
+			self.only_public = Some(meta.clone());
+		} else if path.is_ident(Self::KEEP_ATTRS_IDENT) {
+			let attr_paths = expect_list::<syn::Path>(meta)?;
+
+			if let Some(attr_path) =
+				attr_paths.iter().find(|attr_path| attr_path.is_ident(DissolvedOption::IDENT))
+			{
+				return Err(Error::new_spanned(
+					attr_path,
+					format!(
+						"{} attributes are consumed by the derive and cannot be kept",
+						DissolvedOption::IDENT,
+					),
+				));
+			}
+
+			self.keep_attrs.get_or_insert_with(Vec::new).extend(attr_paths);
+		} else if path.is_ident(Self::SNAPSHOT_IDENT) {
+			// A bare `snapshot` names the method after the option itself
+			let method_name = match meta {
+				Meta::Path(path) => path.require_ident()?.clone(),
+				_ => {
+					let lit_str = expect_str(meta)?;
+
+					parse_dissolved_ident(&lit_str.value(), lit_str.span()).ok_or_else(|| {
+						Error::new_spanned(
+							lit_str,
+							format!(
+								"{} value must be a valid method name, found `{}`",
+								Self::SNAPSHOT_IDENT,
+								lit_str.value(),
+							),
+						)
+					})?
+				},
+			};
+
+			self.snapshot = Some(method_name);
+		} else if path.is_ident(Self::BOXED_IDENT) {
+			expect_flag(meta)?;
+			self.boxed = true;
+		} else if path.is_ident(Self::TRANSPARENT_IDENT) {
+			expect_flag(meta)?;
+			self.transparent = true;
+		} else if path.is_ident(Self::AS_REF_IDENT) {
+			expect_flag(meta)?;
+			self.as_ref = true;
+		} else if path.is_ident(Self::DERIVE_IDENT) {
+			let derive_paths = expect_list::<syn::Path>(meta)?;
+
+			// Deriving `Dissolve` again would dissolve the dissolved type into yet another type
+			let dissolve_path = derive_paths.iter().find(|derive_path| {
+				derive_path.segments.last().is_some_and(|segment| segment.ident == "Dissolve")
+			});
+
+			if let Some(dissolve_path) = dissolve_path {
+				return Err(Error::new_spanned(
+					dissolve_path,
+					"the dissolved type is the terminal form of the original type and cannot \
+					derive Dissolve itself",
+				));
+			}
+
+			self.derives.get_or_insert_with(Vec::new).extend(derive_paths);
+		} else if path.is_ident(Self::METHOD_ATTRS_IDENT) {
+			self.method_attrs.extend(expect_list::<Meta>(meta)?);
+		} else if path.is_ident(Self::FIELD_EXTRACTORS_IDENT) {
+			expect_flag(meta)?;
+			self.field_extractors = true;
+		} else if path.is_ident(Self::DEBUG_GENERATED_IDENT) {
+			expect_flag(meta)?;
+			self.debug_generated = true;
+		} else if path.is_ident(Self::NESTED_IDENT) {
+			expect_flag(meta)?;
+			self.nested = true;
+		} else if path.is_ident(Self::OPAQUE_IDENT) {
+			expect_flag(meta)?;
+			self.opaque = true;
+		} else if path.is_ident(Self::AFTER_IDENT) {
+			self.after = Some(parse_function_path(expect_str(meta)?, Self::AFTER_IDENT)?);
+		} else if path.is_ident(Self::DYNAMIC_IDENT) {
+			expect_flag(meta)?;
+			self.dynamic = true;
+		} else if path.is_ident(Self::TAGGED_IDENT) {
+			expect_flag(meta)?;
+			self.tagged = true;
+		} else if path.is_ident(Self::TUPLE_FROM_IDENT) {
+			expect_flag(meta)?;
+			self.tuple_from = true;
+		} else if path.is_ident(Self::SEAL_IDENT) {
+			expect_flag(meta)?;
+			self.seal = true;
+		} else if path.is_ident(Self::DRAIN_IDENT) {
+			expect_flag(meta)?;
+			self.drain = true;
+		} else if path.is_ident(Self::TRACK_CALLER_IDENT) {
+			expect_flag(meta)?;
+			self.track_caller = true;
+		} else if path.is_ident(Self::TRANSITION_TO_IDENT) {
+			let lit_str = expect_str(meta)?;
+			let target = lit_str.parse::<syn::Path>().map_err(|_| {
+				Error::new_spanned(
+					lit_str,
+					format!(
+						"{} value must be the path of a struct, found `{}`",
+						Self::TRANSITION_TO_IDENT,
+						lit_str.value(),
+					),
+				)
+			})?;
+			self.transition_to = Some(target);
+		} else if path.is_ident(Self::VIA_IDENT) {
+			let fields = expect_list::<syn::Ident>(meta)?;
+
+			if fields.is_empty() {
+				return Err(Error::new_spanned(
+					meta,
+					format!("{} requires at least one field", Self::VIA_IDENT),
+				));
+			}
+
+			self.via.get_or_insert_with(Vec::new).extend(fields);
+		} else if path.is_ident(Self::SERDE_IDENT) {
+			expect_flag(meta)?;
+			self.serde = true;
+		} else if path.is_ident(Self::CFG_IDENT) {
+			let lit_str = expect_str(meta)?;
+			let invalid_predicate = || {
+				Error::new_spanned(
+					lit_str,
+					format!(
+						"{} value must be a cfg predicate such as `feature = \"name\"`, found `{}`",
+						Self::CFG_IDENT,
+						lit_str.value(),
+					),
+				)
+			};
+
+			let predicate = lit_str.parse::<Meta>().map_err(|_| invalid_predicate())?;
+			if !is_cfg_predicate(&predicate) {
+				return Err(invalid_predicate());
+			}
+
+			self.cfg = Some(predicate);
+		} else if path.is_ident(Self::BOXED_SELF_IDENT) {
+			expect_flag(meta)?;
+			self.boxed_self = true;
+		} else if path.is_ident(Self::FROM_ARC_IDENT) {
+			expect_flag(meta)?;
+			self.from_arc = true;
+		} else if path.is_ident(Self::FROM_RC_IDENT) {
+			expect_flag(meta)?;
+			self.from_rc = true;
+		} else if path.is_ident(Self::COPY_VIEW_IDENT) {
+			expect_flag(meta)?;
+			self.copy_view = true;
+		} else if path.is_ident(Self::FINALIZE_IDENT) {
+			self.finalize = Some(parse_function_path(
+				expect_str(meta)?,
+				Self::FINALIZE_IDENT,
+			)?);
+		} else if path.is_ident(Self::NO_AUTO_DOC_IDENT) {
+			expect_flag(meta)?;
+			self.no_auto_doc = true;
+		} else if path.is_ident(Self::TO_JSON_IDENT) {
+			expect_flag(meta)?;
+
+			// The feature is checked here, in the macro: a cfg in the generated code would check
+			// the features of the crate using the derive instead
+			if !cfg!(feature = "serde_json") {
+				return Err(Error::new_spanned(
+					path,
+					format!(
+						"{} requires the `serde_json` feature of dissolve-derive",
+						Self::TO_JSON_IDENT
+					),
+				));
+			}
+
+			self.to_json = true;
+		} else if path.is_ident(Self::ASSERT_SIZE_IDENT) {
+			expect_flag(meta)?;
+			self.assert_size = true;
+		} else if path.is_ident(Self::TERMINAL_IDENT) {
+			expect_flag(meta)?;
+			self.terminal = Some(meta.clone());
+		} else if path.is_ident(Self::METHOD_IDENT) {
+			let lit_str = expect_str(meta)?;
+
+			let method_name =
+				parse_dissolved_ident(&lit_str.value(), lit_str.span()).ok_or_else(|| {
+					Error::new_spanned(
+						lit_str,
+						format!(
+							"{} value must be a valid method name, found `{}`",
+							Self::METHOD_IDENT,
+							lit_str.value(),
+						),
+					)
+				})?;
+
+			self.method = Some(method_name);
+		} else {
+			return Err(Error::new_spanned(
+				path,
+				format!(
+					"unknown dissolve attribute option '{}'; supported options: {}",
+					meta_name(meta),
+					Self::OPTIONS.join(", "),
+				),
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Whether a field attribute is forwarded to the dissolved struct, which only keeps doc
+	/// comments unless `keep_attrs(...)` says otherwise.
+	///
+	/// With `serde`, `#[serde(...)]` attributes are always forwarded.
+	fn keeps_attr(&self, attr: &syn::Attribute) -> bool {
+		if self.serde && attr.path().is_ident(Self::SERDE_IDENT) {
+			return true;
+		}
+
+		match &self.keep_attrs {
+			Some(attr_paths) => attr_paths.iter().any(|attr_path| attr.path() == attr_path),
+			None => attr.path().is_ident("doc"),
+		}
+	}
+
+	/// A kept attribute as written on the dissolved type, where `#[serde(...)]` only applies with
+	/// the consumer's `serde` feature under the `serde` option, like the serde derives themselves.
+	fn forward_attr(&self, attr: &syn::Attribute) -> proc_macro2::TokenStream {
+		if self.serde && attr.path().is_ident(Self::SERDE_IDENT) {
+			let meta = &attr.meta;
+			return quote! { #[cfg_attr(feature = "serde", #meta)] };
+		}
+
+		attr.to_token_stream()
+	}
+
+	/// The generated documentation of the dissolved type or of `dissolve`, unless `no_auto_doc`
+	/// leaves it to the user.
+	fn auto_doc(&self, doc: proc_macro2::TokenStream) -> Option<proc_macro2::TokenStream> {
+		(!self.no_auto_doc).then_some(doc)
+	}
+
+	/// Intra-doc link from a dissolved type to the generated method of `type_name`, or its plain name
+	/// for `dissolve_for!`, which generates a free function rather than a method.
+	fn method_doc_link(&self, type_name: &syn::Ident) -> String {
+		let method_name = self.method_name();
+
+		if self.remote {
+			format!("`{method_name}`")
+		} else {
+			format!("[`{method_name}`]({type_name}::{method_name})")
+		}
+	}
+
+	/// Intra-doc link from the generated method to the dissolved type, or its plain name when
+	/// `opaque` leaves the type out of the documentation.
+	fn dissolved_doc_link(&self, dissolved_name: &syn::Ident) -> String {
+		if self.opaque {
+			format!("`{dissolved_name}`")
+		} else {
+			format!("[`{dissolved_name}`]")
+		}
+	}
+
+	/// The name of the generated `dissolve` method, set with `method = "..."`.
+	fn method_name(&self) -> syn::Ident {
+		self.method.clone().unwrap_or_else(|| format_ident!("{}", Self::METHOD_DEFAULT))
+	}
+
+	/// `#[track_caller]` for the generated methods that dissolve the value, set with
+	/// `track_caller`.
+	fn track_caller_attr(&self) -> Option<proc_macro2::TokenStream> {
+		self.track_caller.then(|| quote! { #[track_caller] })
+	}
+
+	/// The `#[derive(...)]` attributes requested for the dissolved type with `derive(...)` and
+	/// `serde`.
+	fn derives_attr(&self) -> Option<proc_macro2::TokenStream> {
+		let derives = self.derives.as_ref().map(|derives| quote! { #[derive(#(#derives),*)] });
+		let serde = self.serde.then(|| {
+			quote! {
+				#[cfg_attr(
+					feature = "serde",
+					derive(::serde::Serialize, ::serde::Deserialize)
+				)]
+			}
+		});
+
+		(derives.is_some() || serde.is_some()).then(|| quote! { #derives #serde })
+	}
+
+	/// Whether included fields are moved out of local bindings rather than out of `self`.
+	fn moves_by_binding(&self) -> bool {
+		!self.manually_drop && self.move_strategy == MoveStrategy::Destructure
+	}
+
+	/// Checks combinations of options, once all container attributes have been parsed.
+	fn validate(&self) -> Result<()> {
+		if let Some(span) = self.move_strategy_span.filter(|_| self.manually_drop) {
+			return Err(Error::new(
+				span,
+				format!(
+					"{} cannot be combined with {}, which always reads fields in place",
+					Self::MOVE_STRATEGY_IDENT,
+					Self::MANUALLY_DROP_IDENT,
+				),
+			));
+		}
+
+		match (&self.transition_to, &self.via) {
+			(Some(target), None) => {
+				return Err(Error::new_spanned(
+					target,
+					format!(
+						"{} requires {}(...) listing the fields to move",
+						Self::TRANSITION_TO_IDENT,
+						Self::VIA_IDENT,
+					),
+				));
+			},
+			(None, Some(fields)) => {
+				return Err(Error::new_spanned(
+					&fields[0],
+					format!("{} requires {}", Self::VIA_IDENT, Self::TRANSITION_TO_IDENT),
+				));
+			},
+			_ => {},
+		}
+
+		if let Some(meta) = self.only_public.as_ref().filter(|_| self.only.is_some()) {
+			return Err(Error::new_spanned(
+				meta,
+				format!(
+					"{} cannot be combined with {}, which already chooses the included fields",
+					Self::ONLY_PUBLIC_IDENT,
+					Self::ONLY_IDENT,
+				),
+			));
+		}
+
+		Ok(())
+	}
+}
+
+/// Whether `meta` is a well-formed cfg predicate: an option such as `test`, a `key = "value"` pair
+/// or `all`, `any` and `not` of other predicates.
+fn is_cfg_predicate(meta: &Meta) -> bool {
+	match meta {
+		Meta::Path(path) => path.get_ident().is_some(),
+		Meta::NameValue(MetaNameValue { path, value, .. }) => {
+			path.get_ident().is_some()
+				&& matches!(value, Expr::Lit(ExprLit { lit: Lit::Str(_), .. }))
+		},
+		Meta::List(list) => {
+			let Some(operator) = list.path.get_ident() else {
+				return false;
+			};
+
+			let Ok(predicates) = list.parse_args_with(
+				syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+			) else {
+				return false;
+			};
+
+			match operator.to_string().as_str() {
+				"all" | "any" => predicates.iter().all(is_cfg_predicate),
+				"not" => predicates.len() == 1 && predicates.iter().all(is_cfg_predicate),
+				_ => false,
+			}
+		},
+	}
+}
+
+/// Name of an option, as written by the user.
+fn meta_name(meta: &Meta) -> String {
+	meta.path().get_ident().map(|i| i.to_string()).unwrap_or_default()
+}
+
+/// Ensures that a flag-style option such as `recompose` was written without a value.
+fn expect_flag(meta: &Meta) -> Result<()> {
+	match meta {
+		Meta::Path(_) => Ok(()),
+		_ => Err(Error::new_spanned(
+			meta,
+			format!("{} is a flag and does not take a value", meta_name(meta)),
+		)),
+	}
+}
+
+/// Parses the comma-separated items of a list option such as `only("a", "b")`.
+fn expect_list<T: syn::parse::Parse>(
+	meta: &Meta,
+) -> Result<syn::punctuated::Punctuated<T, syn::Token![,]>> {
+	let Meta::List(list) = meta else {
+		let name = meta_name(meta);
+
+		return Err(Error::new_spanned(
+			meta,
+			format!("{name} must use list syntax: {name}(...)"),
+		));
+	};
+
+	list.parse_args_with(syn::punctuated::Punctuated::parse_terminated)
+}
+
+/// Extracts the string value of a name-value option such as `visibility = "pub(crate)"`.
+fn expect_str(meta: &Meta) -> Result<&syn::LitStr> {
+	let name = meta_name(meta);
+
+	let Meta::NameValue(MetaNameValue { value, .. }) = meta else {
+		return Err(Error::new_spanned(
+			meta,
+			format!("{name} must use name-value syntax: {name} = \"...\""),
+		));
+	};
+
+	match value {
+		Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => Ok(lit_str),
+		_ => Err(Error::new_spanned(
+			value,
+			format!("{name} value must be a string literal"),
+		)),
+	}
+}
+
+/// Generates `map_dissolved`, which dissolves `self` and passes the result to a closure.
+fn map_dissolved_method(
+	output_ty: &proc_macro2::TokenStream,
+	generics: &syn::Generics,
+	container_attrs: &ContainerAttributes,
+) -> Option<proc_macro2::TokenStream> {
+	if !container_attrs.map {
+		return None;
+	}
+
+	// Pick a name for the closure's return type that does not shadow a struct type parameter
+	let mut result_ty = format_ident!("R");
+	while generics.type_params().any(|param| param.ident == result_ty) {
+		result_ty = format_ident!("{}_", result_ty);
+	}
+
+	let visibility = &container_attrs.visibility;
+	let method_name = container_attrs.method_name();
+	let track_caller = container_attrs.track_caller_attr();
+
+	Some(quote! {
+		/// Dissolve this struct and pass the dissolved value to `f`, returning its result.
+		#track_caller
+		#visibility fn map_dissolved<#result_ty>(
+			self,
+			f: impl ::core::ops::FnOnce(#output_ty) -> #result_ty,
+		) -> #result_ty {
+			f(self.#method_name())
+		}
+	})
+}
+
+/// Generates the method enabled by `#[dissolve(transition_to = "...", via(...))]`, which dissolves
+/// `self` and builds the target struct from the listed dissolved fields.
+fn transition_method(
+	target: &syn::Path,
+	via: &[syn::Ident],
+	all_fields: &[(&Field, FieldInfo)],
+	returns_carrier: bool,
+	container_attrs: &ContainerAttributes,
+) -> Result<proc_macro2::TokenStream> {
+	let field_inits = via
+		.iter()
+		.map(|name| {
+			// unwrap is safe because struct has named fields
+			let found = all_fields.iter().find(|(field, info)| {
+				let original_name = field.ident.as_ref().unwrap();
+				let listed_name = if info.should_skip {
+					original_name
+				} else {
+					info.dissolved_name(original_name)
+				};
+
+				listed_name.unraw() == name.unraw()
+			});
+
+			let (field, info) = match found {
+				Some((field, info)) if !info.should_skip => (field, info),
+				Some(_) => {
+					return Err(Error::new_spanned(
+						name,
+						format!(
+							"{} lists skipped field `{}`",
+							ContainerAttributes::VIA_IDENT,
+							name.unraw(),
+						),
+					));
+				},
+				None => {
+					return Err(Error::new_spanned(
+						name,
+						format!(
+							"{} lists unknown field `{}`",
+							ContainerAttributes::VIA_IDENT,
+							name.unraw(),
+						),
+					));
+				},
+			};
+
+			// unwrap is safe because struct has named fields
+			let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+			let dissolved_field_path = match &info.group {
+				Some(group) => quote! { #group.#dissolved_field_name },
+				None => dissolved_field_name.to_token_stream(),
+			};
+
+			Ok(if container_attrs.manually_drop {
+				quote! { #name: ::core::mem::ManuallyDrop::into_inner(dissolved.#dissolved_field_path) }
+			} else {
+				quote! { #name: dissolved.#dissolved_field_path }
+			})
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	// unwrap is safe because a parsed path has at least one segment
+	let target_name = &target.segments.last().unwrap().ident;
+	let method_name = format_ident!("into_{}", to_snake_case(&target_name.unraw().to_string()));
+	let visibility = &container_attrs.visibility;
+	let dissolved = bind_dissolved(quote! { self }, returns_carrier, container_attrs);
+
+	// The struct expression needs the turbofish form of any generic arguments
+	let mut target_expr = target.clone();
+	for segment in &mut target_expr.segments {
+		if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+			args.colon2_token.get_or_insert_with(Default::default);
+		}
+	}
+
+	let listed = via.iter().map(|name| format!("`{}`", name.unraw())).collect::<Vec<_>>();
+	let doc = format!(
+		" Dissolve this struct and move its {} fields into a new `{}`.",
+		listed.join(", "),
+		quote! { #target }.to_string().replace(' ', ""),
+	);
+
+	let track_caller = container_attrs.track_caller_attr();
+
+	Ok(quote! {
+		#[doc = #doc]
+		#track_caller
+		#visibility fn #method_name(self) -> #target {
+			#dissolved
+			#target_expr {
+				#(#field_inits),*
+			}
+		}
+	})
+}
+
+/// Generates the method enabled by `#[dissolve(snapshot)]`, which clones the included fields into
+/// a new dissolved value without consuming `self`.
+///
+/// Fields whose type mentions a type parameter get a `Clone` bound on the method rather than on the
+/// struct, so the rest of the impl does not require it.
+fn snapshot_method<'a>(
+	output_ty: &proc_macro2::TokenStream,
+	construction: proc_macro2::TokenStream,
+	field_types: impl IntoIterator<Item = &'a syn::Type>,
+	generics: &syn::Generics,
+	container_attrs: &ContainerAttributes,
+) -> Option<proc_macro2::TokenStream> {
+	let method_name = container_attrs.snapshot.as_ref()?;
+	let clone_bounds = generic_field_bounds(field_types, generics, quote! { ::core::clone::Clone });
+	let visibility = &container_attrs.visibility;
+
+	Some(quote! {
+		/// Clone the included fields into a new dissolved value, leaving `self` untouched.
+		#visibility fn #method_name(&self) -> #output_ty
+		where
+			#(#clone_bounds,)*
+		{
+			#construction
+		}
+	})
+}
+
+/// Generates the `dissolve_ref` method enabled by `#[dissolve(copy_view)]`, which copies the
+/// included fields into a new dissolved value without consuming `self`.
+///
+/// Like `snapshot`, fields whose type mentions a type parameter get a `Copy` bound on the method.
+fn copy_view_method<'a>(
+	output_ty: &proc_macro2::TokenStream,
+	construction: proc_macro2::TokenStream,
+	field_types: impl IntoIterator<Item = &'a syn::Type>,
+	generics: &syn::Generics,
+	container_attrs: &ContainerAttributes,
+) -> Option<proc_macro2::TokenStream> {
+	if !container_attrs.copy_view {
+		return None;
+	}
+
+	let copy_bounds = generic_field_bounds(field_types, generics, quote! { ::core::marker::Copy });
+	let visibility = &container_attrs.visibility;
+
+	Some(quote! {
+		/// Copy the included fields into a new dissolved value, leaving `self` untouched.
+		#visibility fn dissolve_ref(&self) -> #output_ty
+		where
+			#(#copy_bounds,)*
+		{
+			#construction
+		}
+	})
+}
+
+/// Generates the `drain` method enabled by `#[dissolve(drain)]`, which takes the included fields
+/// out of `self` into a new dissolved value, leaving their `Default` values behind.
+///
+/// Like `snapshot`, fields whose type mentions a type parameter get a `Default` bound on the method.
+fn drain_method<'a>(
+	output_ty: &proc_macro2::TokenStream,
+	construction: proc_macro2::TokenStream,
+	field_types: impl IntoIterator<Item = &'a syn::Type>,
+	generics: &syn::Generics,
+	container_attrs: &ContainerAttributes,
+) -> Option<proc_macro2::TokenStream> {
+	if !container_attrs.drain {
+		return None;
+	}
+
+	let default_bounds =
+		generic_field_bounds(field_types, generics, quote! { ::core::default::Default });
+	let visibility = &container_attrs.visibility;
+
+	Some(quote! {
+		/// Take the included fields into a new dissolved value, leaving their default values in
+		/// `self`. Skipped fields are left untouched.
+		#visibility fn drain(&mut self) -> #output_ty
+		where
+			#(#default_bounds,)*
+		{
+			#construction
+		}
+	})
+}
+
+/// `#ty: #bound` predicates for the field types that mention a type parameter, which the methods
+/// reading fields through `&self` need.
+fn generic_field_bounds<'a>(
+	field_types: impl IntoIterator<Item = &'a syn::Type>,
+	generics: &syn::Generics,
+	bound: proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+	let type_params: HashSet<_> =
+		generics.type_params().map(|param| param.ident.to_string()).collect();
+
+	field_types
+		.into_iter()
+		.filter(|ty| !referenced_names(ty).is_disjoint(&type_params))
+		.map(|ty| quote! { #ty: #bound })
+		.collect()
+}
+
+/// Statements binding the unboxed dissolved struct of `receiver` to `dissolved`, for code that
+/// takes its fields apart.
+///
+/// The carrier cannot be rebuilt into anything without the dissolved struct, so it is dropped.
+fn bind_dissolved(
+	receiver: proc_macro2::TokenStream,
+	returns_carrier: bool,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	let method_name = container_attrs.method_name();
+
+	match (returns_carrier, container_attrs.boxed) {
+		(true, true) => {
+			quote! { let (dissolved, _) = #receiver.#method_name(); let dissolved = *dissolved; }
+		},
+		(true, false) => quote! { let (dissolved, _) = #receiver.#method_name(); },
+		(false, true) => quote! { let dissolved = *#receiver.#method_name(); },
+		(false, false) => quote! { let dissolved = #receiver.#method_name(); },
+	}
+}
+
+/// Generates the `dissolve_to_json` method enabled by `#[dissolve(to_json)]`, which serializes the
+/// dissolved value with `serde_json`.
+///
+/// The option itself is only accepted with the `serde_json` feature of this crate, so the method
+/// has no cfg of its own.
+fn to_json_method(
+	returns_carrier: bool,
+	container_attrs: &ContainerAttributes,
+) -> Option<proc_macro2::TokenStream> {
+	if !container_attrs.to_json {
+		return None;
+	}
+
+	let dissolved = bind_dissolved(quote! { self }, returns_carrier, container_attrs);
+	let visibility = &container_attrs.visibility;
+	let track_caller = container_attrs.track_caller_attr();
+
+	Some(quote! {
+		/// Dissolve this value and serialize the dissolved value to a JSON string.
+		#track_caller
+		#visibility fn dissolve_to_json(self) -> ::serde_json::Result<::std::string::String> {
+			#dissolved
+			::serde_json::to_string(&dissolved)
+		}
+	})
+}
+
+/// Generates the `{StructName}Field` enum and the `dissolve_tagged` method enabled by
+/// `#[dissolve(tagged)]`, which returns the included fields in declaration order, each wrapped in
+/// the variant named after its dissolved name.
+///
+/// `fields` holds the dissolved name, the path from the dissolved struct and the original type of
+/// each field. The enum only has the generic parameters its variants use.
+fn tagged_items(
+	struct_name: &syn::Ident,
+	fields: &[(&syn::Ident, proc_macro2::TokenStream, &syn::Type)],
+	returns_carrier: bool,
+	generics: &syn::Generics,
+	container_attrs: &ContainerAttributes,
+) -> Result<Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)>> {
+	if !container_attrs.tagged {
+		return Ok(None);
+	}
+
+	let enum_name = format_ident!("{}Field", struct_name);
+
+	let mut variant_names: Vec<syn::Ident> = Vec::new();
+
+	for (dissolved_field_name, ..) in fields {
+		let variant_name = format_ident!(
+			"{}",
+			to_pascal_case(&dissolved_field_name.unraw().to_string()),
+			span = dissolved_field_name.span()
+		);
+
+		if variant_names.contains(&variant_name) {
+			return Err(Error::new_spanned(
+				dissolved_field_name,
+				format!(
+					"{} would generate the variant `{variant_name}` of `{enum_name}` more than once",
+					ContainerAttributes::TAGGED_IDENT,
+				),
+			));
+		}
+
+		variant_names.push(variant_name);
+	}
+
+	let enum_generics = prune_generics(generics, fields.iter().map(|(_, _, ty)| *ty)).kept;
+	let (impl_generics, ty_generics, where_clause) = enum_generics.split_for_impl();
+
+	let variants = variant_names.iter().zip(fields).map(|(variant_name, (_, _, ty))| {
+		let ty = dissolved_field_type(ty, container_attrs);
+		quote! { #variant_name(#ty) }
+	});
+	let values = variant_names.iter().zip(fields).map(|(variant_name, (_, path, _))| {
+		quote! { #enum_name::#variant_name(dissolved.#path) }
+	});
+
+	let visibility = &container_attrs.visibility;
+	let track_caller = container_attrs.track_caller_attr();
+	let derives = container_attrs.derives_attr();
+	let dissolved = bind_dissolved(quote! { self }, returns_carrier, container_attrs);
+
+	let enum_doc = format!(
+		"A field of [`{struct_name}`], as returned by `dissolve_tagged`.\n\n\
+		There is one variant per included field, named after its dissolved name.",
+	);
+
+	let definition = quote! {
+		#[doc = #enum_doc]
+		#derives
+		#visibility enum #enum_name #impl_generics #where_clause {
+			#(#variants,)*
+		}
+	};
+
+	let method = quote! {
+		/// Dissolve this struct into its included fields, in declaration order, each wrapped in
+		/// the variant named after it.
+		#track_caller
+		#visibility fn dissolve_tagged(self) -> ::std::vec::Vec<#enum_name #ty_generics> {
+			#dissolved
+			::std::vec![#(#values),*]
+		}
+	};
+
+	Ok(Some((definition, method)))
+}
+
+/// Generates the method enabled by `#[dissolve(dynamic)]`, which dissolves `self` and boxes each
+/// field of the dissolved struct into a map keyed by its dissolved name.
+///
+/// `fields` holds the key, the path from the dissolved struct and the original type of each field.
+/// `Any` is only implemented for `'static` types, so the field types that mention a generic
+/// parameter get a `'static` bound on the method.
+fn dynamic_method(
+	fields: &[(String, proc_macro2::TokenStream, &syn::Type)],
+	returns_carrier: bool,
+	generics: &syn::Generics,
+	container_attrs: &ContainerAttributes,
+) -> Option<proc_macro2::TokenStream> {
+	if !container_attrs.dynamic {
+		return None;
+	}
+
+	let param_names: HashSet<_> = generics.params.iter().map(generic_param_name).collect();
+	let static_bounds = fields
+		.iter()
+		.map(|(_, _, ty)| ty)
+		.filter(|ty| !referenced_names(ty).is_disjoint(&param_names))
+		.map(|ty| quote! { #ty: 'static });
+
+	let dissolved = bind_dissolved(quote! { self }, returns_carrier, container_attrs);
+
+	let inserts = fields.iter().map(|(key, path, _)| {
+		quote! {
+			fields.insert(
+				#key,
+				::std::boxed::Box::new(dissolved.#path) as ::std::boxed::Box<dyn ::core::any::Any>,
+			);
+		}
+	});
+	let len = fields.len();
+
+	let visibility = &container_attrs.visibility;
+	let track_caller = container_attrs.track_caller_attr();
+
+	Some(quote! {
+		/// Dissolve this struct into a map from dissolved field names to boxed field values, which
+		/// can be downcast back to the field types.
+		#track_caller
+		#visibility fn dissolve_dynamic(
+			self,
+		) -> ::std::collections::HashMap<&'static str, ::std::boxed::Box<dyn ::core::any::Any>>
+		where
+			#(#static_bounds,)*
+		{
+			#dissolved
+			let mut fields = ::std::collections::HashMap::with_capacity(#len);
+			#(#inserts)*
+			fields
+		}
+	})
+}
+
+/// Implements `AsRef` for the only included field of a struct, as enabled by `#[dissolve(as_ref)]`.
+fn as_ref_impl(
+	struct_name: &syn::Ident,
+	generics: &syn::Generics,
+	included_fields: &[(syn::Member, &syn::Type)],
+	container_attrs: &ContainerAttributes,
+) -> Result<Option<proc_macro2::TokenStream>> {
+	if !container_attrs.as_ref {
+		return Ok(None);
+	}
+
+	let [(member, ty)] = included_fields else {
+		return Err(Error::new_spanned(
+			struct_name,
+			format!(
+				"{} requires exactly one included field, found {}",
+				ContainerAttributes::AS_REF_IDENT,
+				included_fields.len(),
+			),
+		));
+	};
+
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	Ok(Some(quote! {
+		impl #impl_generics ::core::convert::AsRef<#ty> for #struct_name #ty_generics #where_clause {
+			fn as_ref(&self) -> &#ty {
+				&self.#member
+			}
+		}
+	}))
+}
+
+/// Clones an included field for the `snapshot` method, wrapping it like `dissolve` would.
+fn cloned_field(
+	member: &syn::Member,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	wrap_borrowed_field(
+		quote! { ::core::clone::Clone::clone(&self.#member) },
+		container_attrs,
+	)
+}
+
+/// Copies an included field for the `dissolve_ref` method, wrapping it like `dissolve` would.
+fn copied_field(
+	member: &syn::Member,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	// Spanned to the field, so that a field that is not `Copy` is pointed at
+	wrap_borrowed_field(
+		quote_spanned! {member.span()=> self.#member },
+		container_attrs,
+	)
+}
+
+/// Takes an included field for the `drain` method, wrapping it like `dissolve` would.
+fn drained_field(
+	member: &syn::Member,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	// Spanned to the field, so that a field that is not `Default` is pointed at
+	wrap_borrowed_field(
+		quote_spanned! {member.span()=> ::core::mem::take(&mut self.#member) },
+		container_attrs,
+	)
+}
+
+fn wrap_borrowed_field(
+	value: proc_macro2::TokenStream,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	if container_attrs.manually_drop {
+		quote! { ::core::mem::ManuallyDrop::new(#value) }
+	} else {
+		value
+	}
+}
+
+/// Generic parameters of the dissolved struct, split from the ones only used by skipped fields.
+struct PrunedGenerics {
+	/// Parameters referenced by included fields, with bounds and predicates that only involve them
+	kept: syn::Generics,
+	/// Parameters no included field refers to
+	pruned: Vec<syn::GenericParam>,
+}
+
+/// Name a generic parameter is referred to by, e.g. `T`, `'a` or `N`.
+fn generic_param_name(param: &syn::GenericParam) -> String {
+	match param {
+		syn::GenericParam::Type(type_param) => type_param.ident.to_string(),
+		syn::GenericParam::Lifetime(lifetime_param) => lifetime_param.lifetime.to_string(),
+		syn::GenericParam::Const(const_param) => const_param.ident.to_string(),
+	}
+}
+
+/// Collects every identifier and lifetime mentioned in `tokens`.
+///
+/// This over-approximates the generic parameters a type refers to (a path segment that happens to
+/// share a parameter's name counts as a use), which only ever keeps a parameter that could have
+/// been pruned.
+fn collect_referenced_names(tokens: proc_macro2::TokenStream, names: &mut HashSet<String>) {
+	let mut after_apostrophe = false;
+
+	for token in tokens {
+		let is_apostrophe =
+			matches!(&token, proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '\'');
+
+		match token {
+			proc_macro2::TokenTree::Group(group) => collect_referenced_names(group.stream(), names),
+			proc_macro2::TokenTree::Ident(ident) if after_apostrophe => {
+				names.insert(format!("'{ident}"));
+			},
+			proc_macro2::TokenTree::Ident(ident) => {
+				names.insert(ident.to_string());
+			},
+			_ => {},
+		}
+
+		after_apostrophe = is_apostrophe;
+	}
+}
+
+fn referenced_names_in_types<'a>(
+	types: impl IntoIterator<Item = &'a syn::Type>,
+) -> HashSet<String> {
+	let mut names = HashSet::new();
+	for ty in types {
+		collect_referenced_names(ty.to_token_stream(), &mut names);
+	}
+	names
+}
+
+fn referenced_names(tokens: impl ToTokens) -> HashSet<String> {
+	let mut names = HashSet::new();
+	collect_referenced_names(tokens.into_token_stream(), &mut names);
+	names
+}
+
+/// Drops the generic parameters that none of `field_types` refers to, together with the bounds
+/// and where predicates that mention them.
+///
+/// A where predicate is kept only if all the parameters it references are kept, after dropping the
+/// individual bounds that mention a pruned parameter.
+fn prune_generics<'a>(
+	generics: &syn::Generics,
+	field_types: impl IntoIterator<Item = &'a syn::Type>,
+) -> PrunedGenerics {
+	let used = referenced_names_in_types(field_types);
+
+	let (kept_params, pruned): (Vec<_>, Vec<_>) = generics
+		.params
+		.iter()
+		.cloned()
+		.partition(|param| used.contains(&generic_param_name(param)));
+
+	let pruned_names: HashSet<_> = pruned.iter().map(generic_param_name).collect();
+	let mentions_pruned =
+		|tokens: &dyn ToTokens| !referenced_names(tokens).is_disjoint(&pruned_names);
+
+	let kept_params = kept_params.into_iter().map(|mut param| {
+		match &mut param {
+			syn::GenericParam::Type(type_param) => {
+				type_param.bounds = type_param
+					.bounds
+					.iter()
+					.filter(|bound| !mentions_pruned(bound))
+					.cloned()
+					.collect();
+			},
+			syn::GenericParam::Lifetime(lifetime_param) => {
+				lifetime_param.bounds = lifetime_param
+					.bounds
+					.iter()
+					.filter(|bound| !mentions_pruned(bound))
+					.cloned()
+					.collect();
+			},
+			syn::GenericParam::Const(_) => {},
+		}
+
+		param
+	});
+
+	let where_clause = generics.where_clause.as_ref().map(|where_clause| syn::WhereClause {
+		where_token: where_clause.where_token,
+		predicates: where_clause
+			.predicates
+			.iter()
+			.filter_map(|predicate| {
+				// Only the bounds that mention a pruned parameter are dropped, so `T: Clone + Into<U>`
+				// keeps `T: Clone` when `U` is pruned
+				let mut predicate = predicate.clone();
+
+				let has_bounds = match &mut predicate {
+					syn::WherePredicate::Type(predicate_type) => {
+						if mentions_pruned(&predicate_type.bounded_ty) {
+							return None;
+						}
+
+						predicate_type.bounds = predicate_type
+							.bounds
+							.iter()
+							.filter(|bound| !mentions_pruned(bound))
+							.cloned()
+							.collect();
+						!predicate_type.bounds.is_empty()
+					},
+					syn::WherePredicate::Lifetime(predicate_lifetime) => {
+						if mentions_pruned(&predicate_lifetime.lifetime) {
+							return None;
+						}
+
+						predicate_lifetime.bounds = predicate_lifetime
+							.bounds
+							.iter()
+							.filter(|bound| !mentions_pruned(bound))
+							.cloned()
+							.collect();
+						!predicate_lifetime.bounds.is_empty()
+					},
+					_ => !mentions_pruned(&predicate),
+				};
+
+				has_bounds.then_some(predicate)
+			})
+			.collect(),
+	});
+
+	PrunedGenerics {
+		kept: syn::Generics {
+			lt_token: generics.lt_token,
+			params: kept_params.collect(),
+			gt_token: generics.gt_token,
+			where_clause,
+		},
+		pruned,
+	}
+}
+
+/// `PhantomData` field that keeps type and lifetime parameters used only by skipped fields alive,
+/// since a struct must use all of them. Const parameters may go unused, so they are left out.
+///
+/// The parameters sit behind `fn() -> _` since the dissolved struct holds no value of them: they do
+/// not affect its auto traits or drop check, and keep the variance a skipped field would give.
+struct GenericsMarker {
+	name: syn::Ident,
+	ty: proc_macro2::TokenStream,
+}
+
+impl GenericsMarker {
+	fn new(generics: &syn::Generics, fields: &[(&Field, syn::Ident)]) -> Option<Self> {
+		let used = referenced_names_in_types(fields.iter().map(|(field, _)| &field.ty));
+
+		let unused: Vec<_> = generics
+			.params
+			.iter()
+			.filter(|param| !used.contains(&generic_param_name(param)))
+			.filter_map(|param| match param {
+				syn::GenericParam::Type(type_param) => Some(type_param.ident.to_token_stream()),
+				syn::GenericParam::Lifetime(lifetime_param) => {
+					let lifetime = &lifetime_param.lifetime;
+					Some(quote! { &#lifetime () })
+				},
+				syn::GenericParam::Const(_) => None,
+			})
+			.collect();
+
+		if unused.is_empty() {
+			return None;
+		}
+
+		let mut name = format_ident!("_marker");
+		while fields.iter().any(|(_, dissolved_name)| *dissolved_name == name) {
+			name = format_ident!("{}_", name);
+		}
+
+		Some(Self { name, ty: quote! { ::core::marker::PhantomData<fn() -> (#(#unused,)*)> } })
+	}
+}
+
+/// Where clause holding every bound of `generics`, including the ones declared inline on its
+/// parameters, for an item that declares the `declared` parameters itself.
+///
+/// `?Sized` can only relax a parameter of the item it is written on, so it is left out for the
+/// others, which already have it where they are declared.
+fn all_generic_bounds(
+	generics: &syn::Generics,
+	declared: &[syn::GenericParam],
+) -> proc_macro2::TokenStream {
+	let bound =
+		|ident: &syn::Ident,
+		 bounds: &syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]>| {
+			let is_declared = declared.iter().any(
+				|param| matches!(param, syn::GenericParam::Type(type_param) if type_param.ident == *ident),
+			);
+			let bounds: Vec<_> =
+				bounds.iter().filter(|bound| is_declared || !is_maybe_bound(bound)).collect();
+
+			(!bounds.is_empty()).then(|| quote! { #ident: #(#bounds)+* })
+		};
+
+	let inline_bounds = generics.params.iter().filter_map(|param| match param {
+		syn::GenericParam::Type(type_param) => bound(&type_param.ident, &type_param.bounds),
+		syn::GenericParam::Lifetime(lifetime_param) if !lifetime_param.bounds.is_empty() => {
+			let (lifetime, bounds) = (&lifetime_param.lifetime, &lifetime_param.bounds);
+			Some(quote! { #lifetime: #bounds })
+		},
+		_ => None,
+	});
+
+	let predicates = generics.where_clause.iter().flat_map(|where_clause| &where_clause.predicates);
+	let predicates = predicates.filter_map(|predicate| match predicate {
+		syn::WherePredicate::Type(syn::PredicateType {
+			lifetimes: None,
+			bounded_ty: syn::Type::Path(syn::TypePath { qself: None, path }),
+			bounds,
+			..
+		}) => match path.get_ident() {
+			Some(ident) => bound(ident, bounds),
+			None => Some(predicate.to_token_stream()),
+		},
+		_ => Some(predicate.to_token_stream()),
+	});
+
+	quote! { where #(#inline_bounds,)* #(#predicates,)* }
+}
+
+/// Whether `bound` is a relaxed bound such as `?Sized`.
+fn is_maybe_bound(bound: &syn::TypeParamBound) -> bool {
+	matches!(
+		bound,
+		syn::TypeParamBound::Trait(syn::TraitBound {
+			modifier: syn::TraitBoundModifier::Maybe(_),
+			..
+		})
+	)
+}
+
+/// Whether `ty` is a type parameter of `generics`, or a reference, `Box` or `Pin` of one, which
+/// cannot be the target of a `From` impl for a local type.
+fn is_uncovered_type_param(ty: &syn::Type, generics: &syn::Generics) -> bool {
+	match ty {
+		syn::Type::Path(type_path) if type_path.qself.is_none() => {
+			if let Some(ident) = type_path.path.get_ident() {
+				return generics.type_params().any(|param| param.ident == *ident);
+			}
+
+			// `Box` and `Pin` are fundamental, so the orphan rules look through them
+			let Some(last) = type_path.path.segments.last() else {
+				return false;
+			};
+			let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+				return false;
+			};
+
+			match args.args.first() {
+				Some(syn::GenericArgument::Type(inner))
+					if args.args.len() == 1 && (last.ident == "Box" || last.ident == "Pin") =>
+				{
+					is_uncovered_type_param(inner, generics)
+				},
+				_ => false,
+			}
+		},
+		syn::Type::Reference(reference) => is_uncovered_type_param(&reference.elem, generics),
+		syn::Type::Paren(paren) => is_uncovered_type_param(&paren.elem, generics),
+		syn::Type::Group(group) => is_uncovered_type_param(&group.elem, generics),
+		_ => false,
+	}
+}
+
+/// Renders a restricted visibility such as `pub(crate)` or `pub(in crate::module)` as written.
+fn restricted_visibility(restricted: &syn::VisRestricted) -> String {
+	let path = restricted
+		.path
+		.segments
+		.iter()
+		.map(|segment| segment.ident.to_string())
+		.collect::<Vec<_>>()
+		.join("::");
+
+	match restricted.in_token {
+		Some(_) => format!("pub(in {path})"),
+		None => format!("pub({path})"),
+	}
+}
+
+/// Type of an included field in the dissolved output.
+fn dissolved_field_type(
+	ty: &syn::Type,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	if container_attrs.manually_drop {
+		quote! { ::core::mem::ManuallyDrop<#ty> }
+	} else {
+		quote! { #ty }
+	}
+}
+
+/// An included field, as seen by the code moving it out of the struct being dissolved.
+struct FieldMove {
+	/// Field of the original struct.
+	member: syn::Member,
+	/// Local variable the field is bound to when `self` is destructured.
+	binding: syn::Ident,
+	/// Function converting the field into its dissolved value, from `#[dissolved(with = "...")]`.
+	with: Option<syn::Path>,
+}
+
+/// Errors if two included fields end up with the same dissolved name in `scope`.
+///
+/// Fields in different groups end up in different structs, so only names within the same group can
+/// collide.
+fn reject_dissolved_name_collisions(
+	included_fields: &[&(&Field, FieldInfo)],
+	scope: &str,
+) -> Result<()> {
+	let mut dissolved_names: HashMap<_, &syn::Ident> = HashMap::new();
+
+	for (field, info) in included_fields {
+		// unwrap is safe because only named fields are checked
+		let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+		let key = (
+			info.group.as_ref().map(ToString::to_string),
+			dissolved_field_name.unraw().to_string(),
+		);
+
+		if let Some(previous) = dissolved_names.insert(key, dissolved_field_name) {
+			// Point at the rename that caused the collision
+			let span_ident = if info.renamed_to.is_none() {
+				previous
+			} else {
+				dissolved_field_name
+			};
+
+			return Err(Error::new_spanned(
+				span_ident,
+				format!(
+					"field `{}` is defined multiple times in {scope}",
+					dissolved_field_name.unraw(),
+				),
+			));
+		}
+	}
+
+	Ok(())
+}
+
+/// Fields sharing a `#[dissolved(group = "...")]`, nested in their own struct under `name`.
+struct FieldGroup {
+	name: syn::Ident,
+	struct_name: syn::Ident,
+	/// Indices of the fields in the included fields.
+	members: Vec<usize>,
+}
+
+/// Replaces the items of grouped fields by a single item per group, built by `group_item` from the
+/// index of the group and the items of its fields, at the position where the group first appears.
+fn nest_groups(
+	items: Vec<proc_macro2::TokenStream>,
+	groups: &[FieldGroup],
+	mut group_item: impl FnMut(usize, Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+	let mut items: Vec<_> = items.into_iter().map(Some).collect();
+	let mut nested = Vec::new();
+
+	for index in 0..items.len() {
+		match groups.iter().position(|group| group.members.contains(&index)) {
+			// Later fields of a group were already taken along with the first one
+			Some(group_index) if groups[group_index].members[0] == index => {
+				let group_items =
+					groups[group_index].members.iter().filter_map(|&member| items[member].take());
+				nested.push(group_item(group_index, group_items.collect()));
+			},
+			Some(_) => {},
+			// unwrap is safe because only grouped items are taken ahead of their position
+			None => nested.push(items[index].take().unwrap()),
+		}
+	}
+
+	nested
+}
+
+/// Expression that moves an included field out of the struct being dissolved.
+fn move_field(
+	field_move: &FieldMove,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	let FieldMove { member, binding, with } = field_move;
+
+	let moved = if container_attrs.manually_drop {
+		quote! { ::core::ptr::read(&this.#member) }
+	} else if container_attrs.moves_by_binding() {
+		quote! { #binding }
+	} else {
+		quote! { self.#member }
+	};
+	let moved = match with {
+		Some(with) => quote! { #with(#moved) },
+		None => moved,
+	};
+
+	if container_attrs.manually_drop {
+		quote! { ::core::mem::ManuallyDrop::new(#moved) }
+	} else {
+		moved
+	}
+}
+
+/// The `dissolve` method of a type, along with `extra_methods` such as `map_dissolved`.
+///
+/// `attrs` holds the doc comments and other generated attributes of the method. Attributes from
+/// `method_attrs(...)` come after them, so they can override generated lint levels.
+///
+/// For `dissolve_for!`, the type is foreign, so `dissolve` becomes a free function named after it
+/// instead. Its body still takes `self`, so it is wrapped in the impl of a trait local to that
+/// function.
+#[allow(clippy::too_many_arguments)]
+fn dissolve_item(
+	type_name: &syn::Ident,
+	generics: &syn::Generics,
+	attrs: proc_macro2::TokenStream,
+	output_ty: &proc_macro2::TokenStream,
+	body: proc_macro2::TokenStream,
+	extra_methods: proc_macro2::TokenStream,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let visibility = &container_attrs.visibility;
+	let method_name = container_attrs.method_name();
+	let method_attrs = &container_attrs.method_attrs;
+	let track_caller = container_attrs.track_caller_attr();
+	let attrs = quote! {
+		#attrs
+		#track_caller
+		#(#[#method_attrs])*
+	};
+
+	// A `const _` item is evaluated even by `cargo check`, but cannot name generic parameters, so
+	// generic types are checked by an inline `const` block instead, once `dissolve` is compiled for
+	// concrete types
+	let size_assert = |ty: proc_macro2::TokenStream| {
+		quote! {
+			::core::assert!(
+				::core::mem::size_of::<#output_ty>() <= ::core::mem::size_of::<#ty>(),
+				"the value returned by `dissolve` is larger than the value it dissolves",
+			)
+		}
+	};
+	let is_generic = !generics.params.is_empty();
+	let size_check_item = (container_attrs.assert_size && !is_generic).then(|| {
+		let size_assert = size_assert(type_name.to_token_stream());
+		quote! { const _: () = #size_assert; }
+	});
+	let size_check = (container_attrs.assert_size && is_generic).then(|| {
+		let size_assert = size_assert(quote! { Self });
+		quote! { const { #size_assert; } }
+	});
+
+	// The hook borrows `self`, so it runs before any field is moved out
+	let after = container_attrs.after.as_ref().map(|after| quote! { #after(&self); });
+	let body = match &container_attrs.finalize {
+		Some(finalize) => quote! {
+			#size_check
+			#after
+			let dissolved = { #body };
+			#finalize(&dissolved);
+			dissolved
+		},
+		None => quote! {
+			#size_check
+			#after
+			#body
+		},
+	};
+
+	// The body reads `self` by value, so a receiver of any other type hands its value to a local
+	// trait implemented with the body
+	let local_dissolve = |value: proc_macro2::TokenStream| {
+		quote! {
+			trait Dissolve {
+				type Output;
+
+				fn dissolve(self) -> Self::Output;
+			}
+
+			impl #impl_generics Dissolve for #type_name #ty_generics #where_clause {
+				type Output = #output_ty;
+
+				#track_caller
+				fn dissolve(self) -> #output_ty {
+					#body
+				}
+			}
+
+			Dissolve::dissolve(#value)
+		}
+	};
+
+	if container_attrs.boxed_self {
+		let dissolve_boxed = local_dissolve(quote! { *self });
+
+		return quote! {
+			#size_check_item
+
+			impl #impl_generics #type_name #ty_generics #where_clause {
+				#[allow(clippy::boxed_local)]
+				#attrs
+				#visibility fn #method_name(self: ::std::boxed::Box<Self>) -> #output_ty {
+					#dissolve_boxed
+				}
+
+				#extra_methods
+			}
+		};
+	}
+
+	if !container_attrs.remote {
+		let shared_methods = shared_dissolve_methods(output_ty, container_attrs);
+
+		return quote! {
+			#size_check_item
+
+			impl #impl_generics #type_name #ty_generics #where_clause {
+				#attrs
+				#visibility fn #method_name(self) -> #output_ty {
+					#body
+				}
+
+				#extra_methods
+
+				#shared_methods
+			}
+		};
+	}
+
+	let snake_name = to_snake_case(&type_name.unraw().to_string());
+	let function_name = format_ident!(
+		"{}_{}",
+		method_name.unraw(),
+		snake_name,
+		span = type_name.span()
+	);
+	let param = parse_dissolved_ident(&snake_name, type_name.span())
+		.unwrap_or_else(|| format_ident!("value"));
+	let dissolve_param = local_dissolve(param.to_token_stream());
+
+	quote! {
+		#size_check_item
+
+		#attrs
+		#visibility fn #function_name #impl_generics (#param: #type_name #ty_generics) -> #output_ty
+		#where_clause
+		{
+			#dissolve_param
+		}
+	}
+}
+
+/// Generates the methods enabled by `#[dissolve(from_arc)]` and `#[dissolve(from_rc)]`, which
+/// dissolve a value behind an `Arc` or `Rc` if it is the only strong reference to it.
+fn shared_dissolve_methods(
+	output_ty: &proc_macro2::TokenStream,
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	let visibility = &container_attrs.visibility;
+	let method_name = container_attrs.method_name();
+	let track_caller = container_attrs.track_caller_attr();
+
+	// A closure passed to `Result::map` would not forward the caller's location to `dissolve`
+	let arc = container_attrs.from_arc.then(|| {
+		quote! {
+			/// Dissolve the value behind `this` if it is the only strong reference to it.
+			///
+			/// Otherwise `this` is returned unchanged, as [`Arc::try_unwrap`](::std::sync::Arc::try_unwrap)
+			/// does.
+			#track_caller
+			#visibility fn dissolve_arc(
+				this: ::std::sync::Arc<Self>,
+			) -> ::core::result::Result<#output_ty, ::std::sync::Arc<Self>> {
+				match ::std::sync::Arc::try_unwrap(this) {
+					::core::result::Result::Ok(value) => ::core::result::Result::Ok(value.#method_name()),
+					::core::result::Result::Err(this) => ::core::result::Result::Err(this),
+				}
+			}
+		}
+	});
+
+	let rc = container_attrs.from_rc.then(|| {
+		quote! {
+			/// Dissolve the value behind `this` if it is the only strong reference to it.
+			///
+			/// Otherwise `this` is returned unchanged, as [`Rc::try_unwrap`](::std::rc::Rc::try_unwrap)
+			/// does.
+			#track_caller
+			#visibility fn dissolve_rc(
+				this: ::std::rc::Rc<Self>,
+			) -> ::core::result::Result<#output_ty, ::std::rc::Rc<Self>> {
+				match ::std::rc::Rc::try_unwrap(this) {
+					::core::result::Result::Ok(value) => ::core::result::Result::Ok(value.#method_name()),
+					::core::result::Result::Err(this) => ::core::result::Result::Err(this),
+				}
+			}
+		}
+	});
+
+	quote! { #arc #rc }
+}
+
+/// The error for a `#[dissolve(...)]` attribute on a field, suggesting the `#[dissolved(...)]`
+/// spelling when it only holds field options, like `#[dissolve(skip)]`.
+fn misplaced_container_attribute(attr: &syn::Attribute) -> Error {
+	let field_options = match &attr.meta {
+		Meta::List(list) => list
+			.parse_args_with(syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+			.ok()
+			.filter(|metas| metas.iter().all(|meta| DissolvedOption::from_meta(meta).is_ok()))
+			.map(|_| &list.tokens),
+		Meta::Path(_) | Meta::NameValue(_) => None,
+	};
+
+	match field_options {
+		Some(tokens) => Error::new_spanned(
+			attr,
+			format!(
+				"{container} attribute options apply to the whole type, field options go in a {field} attribute: did you mean #[{field}({tokens})]?",
+				container = ContainerAttributes::IDENT,
+				field = DissolvedOption::IDENT,
+			),
+		),
+		None => Error::new_spanned(
+			attr,
+			format!(
+				"{container} attribute cannot be used on a field, field options go in a {field} attribute",
+				container = ContainerAttributes::IDENT,
+				field = DissolvedOption::IDENT,
+			),
+		),
+	}
+}
+
+/// The type a field marked `cow` is dissolved to: a `Cow` borrowing what the field derefs to, e.g.
+/// `Cow<'static, str>` for a `String` and `Cow<'static, [T]>` for a `Vec<T>`.
+///
+/// The value is always owned, so the `'static` lifetime costs nothing: `Cow` is covariant, so the
+/// caller can pass it on wherever a shorter-lived `Cow` is expected.
+fn cow_type(ty: &syn::Type) -> syn::Type {
+	let segment = match ty {
+		syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.segments.last(),
+		_ => None,
+	};
+
+	let borrowed = match segment.map(|segment| (segment.ident.to_string(), &segment.arguments)) {
+		Some((name, syn::PathArguments::None)) if name == "String" => Some(quote! { str }),
+		Some((name, syn::PathArguments::AngleBracketed(args))) if name == "Vec" => {
+			match args.args.first().filter(|_| args.args.len() == 1) {
+				Some(syn::GenericArgument::Type(element)) => Some(quote! { [#element] }),
+				_ => None,
+			}
+		},
+		_ => None,
+	};
+
+	// Other owned types, such as `PathBuf`, borrow the type they deref to
+	let borrowed = borrowed.unwrap_or_else(|| quote! { <#ty as ::core::ops::Deref>::Target });
+
+	syn::parse_quote! { ::std::borrow::Cow<'static, #borrowed> }
+}
+
+/// Parses the value of an option naming a function, such as `after` or `finalize`.
+fn parse_function_path(lit_str: &syn::LitStr, option: &str) -> Result<syn::Path> {
+	lit_str.parse::<syn::Path>().map_err(|_| {
+		Error::new_spanned(
+			lit_str,
+			format!(
+				"{option} value must be the path of a function, found `{}`",
+				lit_str.value(),
+			),
+		)
+	})
+}
+
+/// Converts a group name to pascal case, e.g. `home_address` to `HomeAddress`.
+fn to_pascal_case(name: &str) -> String {
+	name.split('_')
+		.map(|word| {
+			let mut chars = word.chars();
+
+			match chars.next() {
+				Some(first) => first.to_uppercase().chain(chars).collect(),
+				None => String::new(),
+			}
+		})
+		.collect()
+}
+
+/// Converts a type name to snake case, e.g. `HttpServer` and `HTTPServer` to `http_server`.
+fn to_snake_case(name: &str) -> String {
+	let chars: Vec<_> = name.chars().collect();
+	let mut snake = String::new();
+
+	for (index, &ch) in chars.iter().enumerate() {
+		if ch.is_uppercase() && index > 0 {
+			let previous = chars[index - 1];
+			let ends_acronym = previous.is_uppercase()
+				&& chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+			if previous.is_lowercase() || previous.is_ascii_digit() || ends_acronym {
+				snake.push('_');
+			}
+		}
+
+		snake.extend(ch.to_lowercase());
+	}
+
+	snake
+}
+
+/// Body of the `dissolve` method, built around the expression constructing the dissolved value.
+///
+/// With `#[dissolve(manually_drop)]`, `self` is wrapped in `ManuallyDrop` so that its `Drop` impl
+/// never runs. Included fields are read out of it and skipped fields are dropped in place.
+///
+/// With `#[dissolve(move_strategy = "destructure")]`, the included fields are first bound by a
+/// single `let Self { .. } = self;` pattern.
+fn dissolve_body(
+	construction: proc_macro2::TokenStream,
+	field_moves: &[FieldMove],
+	skipped_members: &[syn::Member],
+	container_attrs: &ContainerAttributes,
+) -> proc_macro2::TokenStream {
+	if container_attrs.manually_drop {
+		let binding = if skipped_members.is_empty() {
+			quote! { this }
+		} else {
+			quote! { mut this }
+		};
+
+		return quote! {
+			let #binding = ::core::mem::ManuallyDrop::new(self);
+
+			// SAFETY: `this` is never dropped nor used after this block, so every field is either
+			// read out or dropped in place exactly once.
+			unsafe {
+				#(::core::ptr::drop_in_place(&mut this.#skipped_members);)*
+				#construction
+			}
+		};
+	}
+
+	if container_attrs.moves_by_binding() {
+		let patterns = field_moves.iter().map(|FieldMove { member, binding, .. }| match member {
+			syn::Member::Named(ident) if ident == binding => quote! { #binding },
+			_ => quote! { #member: #binding },
+		});
+
+		return quote! {
+			let Self { #(#patterns,)* .. } = self;
+			#construction
+		};
+	}
+
+	construction
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DissolvedOption {
+	Skip,
+	KeepForRebuild,
+	Keep,
+	Cow,
+	Default(Option<Box<Expr>>),
+	Rename(syn::Ident),
+	RenameWith(Vec<RenameTransform>, syn::LitStr),
+	Group(syn::Ident),
+	Alias(syn::Ident),
+	With(syn::Path),
+	Ty(Box<syn::Type>),
+}
+
+#[derive(Debug, Clone)]
+struct FieldInfo {
+	should_skip: bool,
+	keep_for_rebuild: Option<Meta>,
+	/// Set by `keep`: the skipped field is handed back in the carrier rather than dropped
+	keep: Option<Meta>,
+	/// Set by `default`: `into_original` fills the skipped field with `default_value`, or with
+	/// `Default::default()` if it has none
+	default: Option<Meta>,
+	default_value: Option<Expr>,
+	renamed_to: Option<syn::Ident>,
+	/// Set when `renamed_to` comes from `rename_with` or `rename_all`, whose transforms may produce
+	/// a name that is not snake case
+	renamed_by_transform: bool,
+	group: Option<syn::Ident>,
+	alias: Option<syn::Ident>,
+	/// Function converting the field into its dissolved value, of type `ty`
+	with: Option<syn::Path>,
+	ty: Option<syn::Type>,
+}
+
+impl DissolvedOption {
+	const IDENT: &str = "dissolved";
+
+	const SKIP_IDENT: &str = "skip";
+
+	const KEEP_FOR_REBUILD_IDENT: &str = "keep_for_rebuild";
+
+	const KEEP_IDENT: &str = "keep";
+
+	const RENAME_IDENT: &str = "rename";
+
+	const RENAME_WITH_IDENT: &str = "rename_with";
+
+	const GROUP_IDENT: &str = "group";
+
+	const ALIAS_IDENT: &str = "alias";
+
+	const WITH_IDENT: &str = "with";
+
+	const TY_IDENT: &str = "ty";
+
+	const COW_IDENT: &str = "cow";
+
+	const DEFAULT_IDENT: &str = "default";
+
+	fn from_meta(meta: &Meta) -> Result<Self> {
+		let unknown_attribute_err = |path: &syn::Path| {
+			let path_str = path
+				.segments
+				.iter()
+				.map(|seg| seg.ident.to_string())
+				.collect::<Vec<_>>()
+				.join("::");
+
+			Error::new_spanned(
+				path,
+				format!(
+					"unknown dissolved attribute option '{}'; supported options: {}, {}, {}, {}, {}, {} = \"expr\", {} = \"new_name\", {} = \"transform\", {} = \"name\", {} = \"old_name\", {} = \"path::to::fn\", {} = \"Type\"",
+					path_str,
+					Self::SKIP_IDENT,
+					Self::KEEP_FOR_REBUILD_IDENT,
+					Self::KEEP_IDENT,
+					Self::COW_IDENT,
+					Self::DEFAULT_IDENT,
+					Self::DEFAULT_IDENT,
+					Self::RENAME_IDENT,
+					Self::RENAME_WITH_IDENT,
+					Self::GROUP_IDENT,
+					Self::ALIAS_IDENT,
+					Self::WITH_IDENT,
+					Self::TY_IDENT,
+				),
+			)
+		};
+
+		let opt = match meta {
+			Meta::Path(path) => {
+				if path.is_ident(Self::SKIP_IDENT) {
+					DissolvedOption::Skip
+				} else if path.is_ident(Self::KEEP_FOR_REBUILD_IDENT) {
+					DissolvedOption::KeepForRebuild
+				} else if path.is_ident(Self::KEEP_IDENT) {
+					DissolvedOption::Keep
+				} else if path.is_ident(Self::COW_IDENT) {
+					DissolvedOption::Cow
+				} else if path.is_ident(Self::DEFAULT_IDENT) {
+					DissolvedOption::Default(None)
+				} else {
+					return Err(unknown_attribute_err(path));
+				}
+			},
+			Meta::NameValue(MetaNameValue { path, value, .. }) => {
+				let option_name = if path.is_ident(Self::RENAME_IDENT) {
+					Self::RENAME_IDENT
+				} else if path.is_ident(Self::RENAME_WITH_IDENT) {
+					Self::RENAME_WITH_IDENT
+				} else if path.is_ident(Self::GROUP_IDENT) {
+					Self::GROUP_IDENT
+				} else if path.is_ident(Self::ALIAS_IDENT) {
+					Self::ALIAS_IDENT
+				} else if path.is_ident(Self::WITH_IDENT) {
+					Self::WITH_IDENT
+				} else if path.is_ident(Self::TY_IDENT) {
+					Self::TY_IDENT
+				} else if path.is_ident(Self::DEFAULT_IDENT) {
+					Self::DEFAULT_IDENT
+				} else {
+					return Err(unknown_attribute_err(path));
+				};
+
+				let Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) = value else {
+					return Err(Error::new_spanned(
+						value,
+						format!("{option_name} value must be a string literal"),
+					));
+				};
+
+				// Caught here, as the parsers below would report an empty value as a confusing
+				// "found ``"
+				if lit_str.value().trim().is_empty() {
+					return Err(Error::new_spanned(
+						lit_str,
+						format!("{option_name} value cannot be empty"),
+					));
+				}
+
+				if option_name == Self::RENAME_IDENT {
+					parse_rename_target(lit_str).map(DissolvedOption::Rename)?
+				} else if option_name == Self::WITH_IDENT {
+					parse_function_path(lit_str, Self::WITH_IDENT).map(DissolvedOption::With)?
+				} else if option_name == Self::TY_IDENT {
+					let ty = lit_str.parse::<syn::Type>().map_err(|_| {
+						Error::new_spanned(
+							lit_str,
+							format!(
+								"{} value must be a type, found `{}`",
+								Self::TY_IDENT,
+								lit_str.value(),
+							),
+						)
+					})?;
+
+					DissolvedOption::Ty(Box::new(ty))
+				} else if option_name == Self::DEFAULT_IDENT {
+					let value = lit_str.parse::<Expr>().map_err(|_| {
+						Error::new_spanned(
+							lit_str,
+							format!(
+								"{} value must be an expression, found `{}`",
+								Self::DEFAULT_IDENT,
+								lit_str.value(),
+							),
+						)
+					})?;
+
+					DissolvedOption::Default(Some(Box::new(value)))
+				} else if option_name == Self::GROUP_IDENT {
+					parse_group_name(lit_str).map(DissolvedOption::Group)?
+				} else if option_name == Self::ALIAS_IDENT {
+					let alias = parse_dissolved_ident(&lit_str.value(), lit_str.span());
+
+					DissolvedOption::Alias(alias.ok_or_else(|| {
+						Error::new_spanned(
+							lit_str,
+							format!(
+								"{} value must be a valid method name, found `{}`",
+								Self::ALIAS_IDENT,
+								lit_str.value(),
+							),
+						)
+					})?)
+				} else {
+					let transforms =
+						RenameTransform::parse_all(lit_str, DissolvedOption::RENAME_WITH_IDENT)?;
+					DissolvedOption::RenameWith(transforms, lit_str.clone())
+				}
+			},
+			Meta::List(_) => {
+				return Err(Error::new_spanned(
+					meta,
+					"nested lists are not supported in dissolved attributes",
+				));
+			},
+		};
+
+		Ok(opt)
+	}
+}
+
+/// Parses the identifier a field is renamed to.
+///
+/// Keywords are accepted and turned into raw identifiers, so `rename = "type"` produces the same
+/// field as `rename = "r#type"`.
+fn parse_rename_target(lit_str: &syn::LitStr) -> Result<syn::Ident> {
+	let value = lit_str.value();
+
+	parse_dissolved_ident(&value, lit_str.span()).ok_or_else(|| {
+		Error::new_spanned(
+			lit_str,
+			format!(
+				"{} value must be a single valid identifier, found `{value}`",
+				DissolvedOption::RENAME_IDENT,
+			),
+		)
+	})
+}
+
+/// Parses the name of the group a field is nested under.
+///
+/// The name becomes a field of the dissolved struct, so it must be a plain identifier.
+fn parse_group_name(lit_str: &syn::LitStr) -> Result<syn::Ident> {
+	let value = lit_str.value();
+
+	if syn::parse_str::<syn::Ident>(&value).is_ok() {
+		return Ok(syn::Ident::new(&value, lit_str.span()));
+	}
+
+	Err(Error::new_spanned(
+		lit_str,
+		format!(
+			"{} value must be a single valid identifier, found `{value}`",
+			DissolvedOption::GROUP_IDENT,
+		),
+	))
+}
+
+/// Turns `value` into a dissolved field identifier, using a raw identifier for keywords.
+fn parse_dissolved_ident(value: &str, span: proc_macro2::Span) -> Option<syn::Ident> {
+	// These keywords cannot be used as raw identifiers
+	const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+	if let Ok(mut ident) = syn::parse_str::<syn::Ident>(value) {
+		ident.set_span(span);
+		return Some(ident);
+	}
+
+	match syn::parse::Parser::parse_str(syn::Ident::parse_any, value) {
+		Ok(_) if !NON_RAW_KEYWORDS.contains(&value) => Some(syn::Ident::new_raw(value, span)),
+		_ => None,
+	}
+}
+
+/// A built-in name transform used by `#[dissolved(rename_with = "...")]`.
+///
+/// Proc macros cannot call user functions, so computed names are limited to this fixed set.
+/// Transforms are chained with `|` and applied left to right.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RenameTransform {
+	Upper,
+	Lower,
+	StripPrefix(String),
+	StripSuffix(String),
+	Prefix(String),
+	Suffix(String),
+}
+
+impl RenameTransform {
+	const UPPER_IDENT: &str = "upper";
+
+	const LOWER_IDENT: &str = "lower";
+
+	const STRIP_PREFIX_IDENT: &str = "strip_prefix";
+
+	const STRIP_SUFFIX_IDENT: &str = "strip_suffix";
+
+	const PREFIX_IDENT: &str = "prefix";
+
+	const SUFFIX_IDENT: &str = "suffix";
+
+	/// Each transform with the placeholder of its argument, if it takes one.
+	const TRANSFORMS: &[(&str, Option<&str>)] = &[
+		(Self::UPPER_IDENT, None),
+		(Self::LOWER_IDENT, None),
+		(Self::STRIP_PREFIX_IDENT, Some("prefix")),
+		(Self::STRIP_SUFFIX_IDENT, Some("suffix")),
+		(Self::PREFIX_IDENT, Some("prefix")),
+		(Self::SUFFIX_IDENT, Some("suffix")),
+	];
+
+	/// Parses the `|`-separated transforms of `option`, either `rename_with` or `rename_all`.
+	fn parse_all(lit_str: &syn::LitStr, option: &str) -> Result<Vec<Self>> {
+		let value = lit_str.value();
+		let supported = Self::TRANSFORMS
+			.iter()
+			.map(|(name, arg)| match arg {
+				Some(arg) => format!("{name}:<{arg}>"),
+				None => (*name).to_owned(),
+			})
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		value
+			.split('|')
+			.map(|step| {
+				Self::parse(step.trim()).ok_or_else(|| {
+					Error::new_spanned(
+						lit_str,
+						format!(
+							"unknown {option} transform `{}`; supported transforms: {}",
+							step.trim(),
+							supported,
+						),
+					)
+				})
+			})
+			.collect()
+	}
+
+	fn parse(step: &str) -> Option<Self> {
+		let (name, arg) = match step.split_once(':') {
+			Some((name, arg)) if !arg.is_empty() => (name, Some(arg.to_owned())),
+			Some(_) => return None,
+			None => (step, None),
+		};
+
+		match (name, arg) {
+			(Self::UPPER_IDENT, None) => Some(Self::Upper),
+			(Self::LOWER_IDENT, None) => Some(Self::Lower),
+			(Self::STRIP_PREFIX_IDENT, Some(arg)) => Some(Self::StripPrefix(arg)),
+			(Self::STRIP_SUFFIX_IDENT, Some(arg)) => Some(Self::StripSuffix(arg)),
+			(Self::PREFIX_IDENT, Some(arg)) => Some(Self::Prefix(arg)),
+			(Self::SUFFIX_IDENT, Some(arg)) => Some(Self::Suffix(arg)),
+			_ => None,
+		}
+	}
+
+	/// Applies the transform, returning `None` if a prefix or suffix to strip is missing.
+	fn apply(&self, name: &str) -> Option<String> {
+		match self {
+			Self::Upper => Some(name.to_uppercase()),
+			Self::Lower => Some(name.to_lowercase()),
+			Self::StripPrefix(prefix) => name.strip_prefix(prefix.as_str()).map(str::to_owned),
+			Self::StripSuffix(suffix) => name.strip_suffix(suffix.as_str()).map(str::to_owned),
+			Self::Prefix(prefix) => Some(format!("{prefix}{name}")),
+			Self::Suffix(suffix) => Some(format!("{name}{suffix}")),
+		}
+	}
+}
+
+/// Computes the dissolved name of a named field from the transforms of `option`, either its own
+/// `rename_with` or the container's `rename_all`.
+fn apply_rename_transforms(
+	field: &Field,
+	transforms: &[RenameTransform],
+	lit_str: &syn::LitStr,
+	option: &str,
+) -> Result<syn::Ident> {
+	let Some(ident) = &field.ident else {
+		return Err(Error::new_spanned(
+			field,
+			format!(
+				"{} is unsupported for tuple struct fields, only {} is allowed",
+				DissolvedOption::RENAME_WITH_IDENT,
+				DissolvedOption::SKIP_IDENT,
+			),
+		));
+	};
+
+	let original_name = ident.unraw().to_string();
+	let mut name = original_name.clone();
+
+	for transform in transforms {
+		name = transform.apply(&name).ok_or_else(|| {
+			Error::new_spanned(
+				lit_str,
+				format!(
+					"cannot apply {option} = \"{}\" to field `{original_name}`: `{name}` does not have the prefix or suffix to strip",
+					lit_str.value(),
+				),
+			)
+		})?;
+	}
+
+	parse_dissolved_ident(&name, lit_str.span()).ok_or_else(|| {
+		Error::new_spanned(
+			lit_str,
+			format!(
+				"{option} turned field `{original_name}` into `{name}`, which is not a valid identifier",
+			),
+		)
+	})
+}
+
+/// `#[allow(non_snake_case)]` for the generated items that take the names of these fields, when
+/// a transform such as `upper` named one of them.
+///
+/// The lint reads its level from the struct, enum or function rather than from the field itself.
+fn allow_transformed_names<'a>(
+	infos: impl IntoIterator<Item = &'a FieldInfo>,
+) -> Option<proc_macro2::TokenStream> {
+	let transformed = infos.into_iter().any(|info| info.renamed_by_transform);
+
+	transformed.then(|| quote! { #[allow(non_snake_case)] })
+}
+
+impl FieldInfo {
+	/// Name of the field in the dissolved struct, taking `rename` into account.
+	fn dissolved_name<'a>(&'a self, original_name: &'a syn::Ident) -> &'a syn::Ident {
+		self.renamed_to.as_ref().unwrap_or(original_name)
+	}
+
+	/// The `keep_for_rebuild` or `keep` option moving this skipped field into the carrier.
+	fn carried(&self) -> Option<&Meta> {
+		self.keep_for_rebuild.as_ref().or(self.keep.as_ref())
+	}
+
+	/// Errors if the field is marked `keep_for_rebuild`, `keep`, `default`, `group` or `alias`,
+	/// which only named structs support.
+	fn reject_named_only_options(&self, fields_kind: &str) -> Result<()> {
+		for (meta, option_name) in [
+			(
+				&self.keep_for_rebuild,
+				DissolvedOption::KEEP_FOR_REBUILD_IDENT,
+			),
+			(&self.keep, DissolvedOption::KEEP_IDENT),
+			(&self.default, DissolvedOption::DEFAULT_IDENT),
+		] {
+			if let Some(meta) = meta {
+				return Err(Error::new_spanned(
+					meta,
+					format!("{option_name} is unsupported for {fields_kind} fields"),
+				));
+			}
+		}
+
+		if let Some(group) = &self.group {
+			return Err(Error::new_spanned(
+				group,
+				format!(
+					"{} is unsupported for {fields_kind} fields",
+					DissolvedOption::GROUP_IDENT,
+				),
+			));
+		}
+
+		if let Some(alias) = &self.alias {
+			return Err(Error::new_spanned(
+				alias,
+				format!(
+					"{} is unsupported for {fields_kind} fields",
+					DissolvedOption::ALIAS_IDENT,
+				),
+			));
+		}
+
+		Ok(())
+	}
+}
+
+/// Generates the dissolve impl of a type, or the free function of `dissolve_for!` if `remote`.
+fn generate_dissolve_impl(input: &DeriveInput, remote: bool) -> Result<proc_macro2::TokenStream> {
+	let struct_name = &input.ident;
+	let generics = &input.generics;
+	let container_attrs =
+		ContainerAttributes { remote, ..ContainerAttributes::from_derive_input(input)? };
+
+	if container_attrs.terminal.is_some() {
+		return terminal_impl(input);
+	}
+
+	// These options add methods to the type itself, which is not possible for a foreign type
+	let method_options = [
+		(container_attrs.map, ContainerAttributes::MAP_IDENT),
+		(
+			container_attrs.snapshot.is_some(),
+			ContainerAttributes::SNAPSHOT_IDENT,
+		),
+		(container_attrs.as_ref, ContainerAttributes::AS_REF_IDENT),
+		(container_attrs.dynamic, ContainerAttributes::DYNAMIC_IDENT),
+		(container_attrs.tagged, ContainerAttributes::TAGGED_IDENT),
+		(
+			container_attrs.tuple_from,
+			ContainerAttributes::TUPLE_FROM_IDENT,
+		),
+		(
+			container_attrs.boxed_self,
+			ContainerAttributes::BOXED_SELF_IDENT,
+		),
+		(
+			container_attrs.from_arc,
+			ContainerAttributes::FROM_ARC_IDENT,
+		),
+		(container_attrs.from_rc, ContainerAttributes::FROM_RC_IDENT),
+		(
+			container_attrs.copy_view,
+			ContainerAttributes::COPY_VIEW_IDENT,
+		),
+		(container_attrs.drain, ContainerAttributes::DRAIN_IDENT),
+		(container_attrs.to_json, ContainerAttributes::TO_JSON_IDENT),
+		(
+			container_attrs.transition_to.is_some(),
+			ContainerAttributes::TRANSITION_TO_IDENT,
+		),
+	];
+
+	if let Some((_, option)) =
+		method_options.iter().find(|(enabled, _)| *enabled && container_attrs.remote)
+	{
+		return Err(Error::new_spanned(
+			struct_name,
+			format!(
+				"{option} is not supported by dissolve_for!, as it adds methods to the foreign type",
+			),
+		));
+	}
+
+	// These call `dissolve` on `self` or on a value, which cannot be done without boxing it first
+	let by_value_options = [
+		(container_attrs.map, ContainerAttributes::MAP_IDENT),
+		(container_attrs.newtype, ContainerAttributes::NEWTYPE_IDENT),
+		(container_attrs.dynamic, ContainerAttributes::DYNAMIC_IDENT),
+		(container_attrs.tagged, ContainerAttributes::TAGGED_IDENT),
+		(
+			container_attrs.tuple_from,
+			ContainerAttributes::TUPLE_FROM_IDENT,
+		),
+		(
+			container_attrs.from_arc,
+			ContainerAttributes::FROM_ARC_IDENT,
+		),
+		(container_attrs.from_rc, ContainerAttributes::FROM_RC_IDENT),
+		(container_attrs.to_json, ContainerAttributes::TO_JSON_IDENT),
+		(
+			container_attrs.transition_to.is_some(),
+			ContainerAttributes::TRANSITION_TO_IDENT,
+		),
+	];
+
+	if let Some((_, option)) =
+		by_value_options.iter().find(|(enabled, _)| *enabled && container_attrs.boxed_self)
+	{
+		return Err(Error::new_spanned(
+			struct_name,
+			format!(
+				"{option} cannot be combined with {}, as it dissolves a value that is not boxed",
+				ContainerAttributes::BOXED_SELF_IDENT,
+			),
+		));
+	}
+
+	// Fields converted by a `with` function are dissolved as the type it returns, so every
+	// generator sees that type as the type of the field
+	let input = &with_dissolved_field_types(input, &container_attrs);
+
+	let is_tuple_struct = matches!(
+		&input.data,
+		Data::Struct(syn::DataStruct { fields: Fields::Unnamed(_), .. })
+	);
+
+	if let Some(span) = container_attrs.tuple_output_span.filter(|_| !is_tuple_struct) {
+		return Err(Error::new(
+			span,
+			format!(
+				"{} is only supported for tuple structs",
+				ContainerAttributes::OUTPUT_IDENT
+			),
+		));
+	}
+
+	let generated = match &input.data {
+		Data::Struct(data_struct) => match &data_struct.fields {
+			Fields::Named(fields) if container_attrs.newtype => {
+				let (fields, original_members) = unnamed_newtype_fields(fields);
+
+				generate_tuple_struct_impl(
+					struct_name,
+					generics,
+					&fields,
+					&original_members,
+					&container_attrs,
+				)?
+			},
+			Fields::Named(fields) => {
+				let original_members: Vec<_> = fields
+					.named
+					.iter()
+					// unwrap is safe because struct has named fields
+					.map(|field| syn::Member::Named(field.ident.clone().unwrap()))
+					.collect();
+
+				generate_named_struct_impl(
+					struct_name,
+					generics,
+					fields,
+					&original_members,
+					&container_attrs,
+				)?
+			},
+			Fields::Unnamed(fields) if container_attrs.tuple_output == TupleOutput::Struct => {
+				let (fields, original_members) = named_tuple_fields(fields);
+
+				generate_named_struct_impl(
+					struct_name,
+					generics,
+					&fields,
+					&original_members,
+					&container_attrs,
+				)?
+			},
+			Fields::Unnamed(fields) => {
+				let original_members: Vec<_> = (0..fields.unnamed.len())
+					.map(|index| syn::Member::Unnamed(Index::from(index)))
+					.collect();
+
+				generate_tuple_struct_impl(
+					struct_name,
+					generics,
+					fields,
+					&original_members,
+					&container_attrs,
+				)?
+			},
+			Fields::Unit => {
+				return Err(Error::new_spanned(
+					input,
+					"Dissolve cannot be derived for unit structs",
+				));
+			},
+		},
+		Data::Enum(data_enum) => {
+			generate_enum_impl(struct_name, generics, data_enum, &container_attrs)?
+		},
+		Data::Union(_) => {
+			return Err(Error::new_spanned(
+				input,
+				"Dissolve can only be derived for structs and enums",
+			));
+		},
+	};
+
+	// A single `#[cfg]` cannot cover several items, so each generated item gets its own
+	let generated = match &container_attrs.cfg {
+		Some(predicate) => {
+			let items = syn::parse2::<syn::File>(generated)?.items;
+			quote! { #(#[cfg(#predicate)] #items)* }
+		},
+		None => generated,
+	};
+
+	if container_attrs.debug_generated {
+		// One generated item per line, which is easier to follow than a single line of tokens
+		let expansion = match syn::parse2::<syn::File>(generated.clone()) {
+			Ok(file) => file
+				.items
+				.iter()
+				.map(|item| item.to_token_stream().to_string())
+				.collect::<Vec<_>>()
+				.join("\n"),
+			Err(_) => generated.to_string(),
+		};
+
+		eprintln!("dissolve-derive expansion for `{struct_name}`:\n{expansion}");
+	}
+
+	Ok(generated)
+}
+
+/// Output of the derive for a type marked `#[dissolve(terminal)]`: nothing, once it is checked that
+/// no field carries options that would be silently ignored.
+fn terminal_impl(input: &DeriveInput) -> Result<proc_macro2::TokenStream> {
+	let fields: Vec<&syn::Field> = match &input.data {
+		Data::Struct(data_struct) => data_struct.fields.iter().collect(),
+		Data::Enum(data_enum) => data_enum.variants.iter().flat_map(|v| &v.fields).collect(),
+		Data::Union(data_union) => data_union.fields.named.iter().collect(),
+	};
+
+	let field_attr = fields
+		.iter()
+		.flat_map(|field| &field.attrs)
+		.find(|attr| attr.path().is_ident(DissolvedOption::IDENT));
+
+	match field_attr {
+		Some(attr) => Err(Error::new_spanned(
+			attr,
+			format!(
+				"{} attributes have no effect on a type marked {}",
+				DissolvedOption::IDENT,
+				ContainerAttributes::TERMINAL_IDENT,
+			),
+		)),
+		None => Ok(proc_macro2::TokenStream::new()),
+	}
+}
+
+/// Names the fields of a tuple struct `field_{index}`, for `output = "struct"`, along with the
+/// original member of each field.
+fn named_tuple_fields(fields: &FieldsUnnamed) -> (syn::FieldsNamed, Vec<syn::Member>) {
+	let named = fields
+		.unnamed
+		.iter()
+		.enumerate()
+		.map(|(index, field)| syn::Field {
+			ident: Some(format_ident!("field_{}", index, span = field.span())),
+			colon_token: Some(Default::default()),
+			..field.clone()
+		})
+		.collect();
+
+	let original_members =
+		(0..fields.unnamed.len()).map(|index| syn::Member::Unnamed(Index::from(index))).collect();
+
+	(
+		syn::FieldsNamed { brace_token: Default::default(), named },
+		original_members,
+	)
+}
+
+/// Drops the names of the fields of a named struct dissolved with `newtype`, which dissolves like a
+/// tuple struct, along with the original member of each field.
+fn unnamed_newtype_fields(fields: &syn::FieldsNamed) -> (FieldsUnnamed, Vec<syn::Member>) {
+	let unnamed =
+		fields.named.iter().map(|field| Field { ident: None, colon_token: None, ..field.clone() });
+
+	// unwrap is safe because struct has named fields
+	let original_members =
+		fields.named.iter().map(|field| syn::Member::Named(field.ident.clone().unwrap())).collect();
+
+	(
+		FieldsUnnamed { paren_token: Default::default(), unnamed: unnamed.collect() },
+		original_members,
+	)
+}
+
+/// Replaces the type of every field marked `#[dissolved(ty = "...")]` with the given type.
+///
+/// Invalid field attributes are left as they are, for the generators to report.
+fn with_dissolved_field_types(
+	input: &DeriveInput,
+	container_attrs: &ContainerAttributes,
+) -> DeriveInput {
+	let mut input = input.clone();
+
+	let fields: Box<dyn Iterator<Item = &mut Field>> = match &mut input.data {
+		Data::Struct(data_struct) => Box::new(data_struct.fields.iter_mut()),
+		Data::Enum(data_enum) => {
+			Box::new(data_enum.variants.iter_mut().flat_map(|variant| variant.fields.iter_mut()))
+		},
+		Data::Union(_) => Box::new(std::iter::empty()),
+	};
+
+	for field in fields {
+		if let Ok(FieldInfo { ty: Some(ty), .. }) = get_field_info(field, container_attrs) {
+			field.ty = ty;
+		}
+	}
+
+	input
+}
+
+/// Generates the dissolved struct and its impls for a struct with named fields.
+///
+/// `original_members` holds the member of each field in the struct being dissolved, which differs
+/// from the field name for a tuple struct dissolved with `output = "struct"`.
+fn generate_named_struct_impl(
+	struct_name: &syn::Ident,
+	generics: &syn::Generics,
+	fields: &syn::FieldsNamed,
+	original_members: &[syn::Member],
+	container_attrs: &ContainerAttributes,
+) -> Result<proc_macro2::TokenStream> {
+	let original_member = |field: &Field| {
+		let position = fields.named.iter().position(|named| named.ident == field.ident);
+
+		// unwrap is safe because field names are unique and `field` is one of `fields`
+		original_members[position.unwrap()].clone()
+	};
+
+	if container_attrs.array {
+		return Err(Error::new_spanned(
+			struct_name,
+			format!(
+				"{} is only supported for tuple structs",
+				ContainerAttributes::ARRAY_IDENT
+			),
+		));
+	}
+
+	let all_fields = fields
+		.named
+		.iter()
+		.map(|field| {
+			let mut info = get_field_info(field, container_attrs)?;
+
+			apply_container_selection(field, &mut info, container_attrs)?;
+
+			Ok((field, info))
+		})
+		.collect::<Result<Vec<_>>>()?;
+
+	if let Some(only) = &container_attrs.only {
+		for name in only {
+			let value = only_field_name(name);
+
+			// unwrap is safe because struct has named fields
+			if !fields.named.iter().any(|field| field.ident.as_ref().unwrap().unraw() == value) {
+				return Err(Error::new_spanned(
+					name,
+					format!(
+						"{} lists unknown field `{value}`",
+						ContainerAttributes::ONLY_IDENT
+					),
+				));
+			}
+		}
+	}
+
+	let included_fields: Vec<_> = all_fields.iter().filter(|(_, info)| !info.should_skip).collect();
+
+	// A skipped field renamed by a transform names a parameter of `into_original`
+	let allow_non_snake_case = allow_transformed_names(all_fields.iter().map(|(_, info)| info));
+
+	if included_fields.is_empty() {
+		return Err(Error::new_spanned(
+			struct_name,
+			"cannot create dissolved struct with no fields (all fields are skipped)",
+		));
+	}
+
+	let as_ref_impl = as_ref_impl(
+		struct_name,
+		generics,
+		&included_fields
+			.iter()
+			.map(|(field, _)| (original_member(field), &field.ty))
+			.collect::<Vec<_>>(),
+		container_attrs,
+	)?;
+
+	if container_attrs.transparent && included_fields.len() > 1 {
+		return Err(Error::new_spanned(
+			struct_name,
+			format!(
+				"{} requires exactly one included field, found {}",
+				ContainerAttributes::TRANSPARENT_IDENT,
+				included_fields.len(),
+			),
+		));
+	}
+
+	let carried_fields: Vec<_> =
+		all_fields.iter().filter(|(_, info)| info.carried().is_some()).collect();
+
+	// Fields kept for rebuild can only be put back by `into_original`
+	if let Some(meta) = carried_fields
+		.iter()
+		.find_map(|(_, info)| info.keep_for_rebuild.as_ref())
+		.filter(|_| !container_attrs.recompose)
+	{
+		return Err(Error::new_spanned(
+			meta,
+			format!(
+				"{} requires #[dissolve({})]",
+				DissolvedOption::KEEP_FOR_REBUILD_IDENT,
+				ContainerAttributes::RECOMPOSE_IDENT,
+			),
+		));
+	}
+
+	// Without skipped fields, the dissolved struct holds everything needed to rebuild the original
+	if let Some((field, _)) = all_fields
+		.iter()
+		.find(|(_, info)| info.should_skip)
+		.filter(|_| container_attrs.from_dissolved)
+	{
+		// unwrap is safe because struct has named fields
+		let field_name = field.ident.as_ref().unwrap();
+
+		return Err(Error::new_spanned(
+			field_name,
+			format!(
+				"{} cannot rebuild `{struct_name}` from its dissolved struct, which does not hold the skipped field `{}`",
+				ContainerAttributes::FROM_DISSOLVED_IDENT,
+				field_name.unraw(),
+			),
+		));
+	}
+
+	// Only `into_original` fills in defaulted fields
+	if let Some(meta) = all_fields
+		.iter()
+		.find_map(|(_, info)| info.default.as_ref())
+		.filter(|_| !container_attrs.recompose)
+	{
+		return Err(Error::new_spanned(
+			meta,
+			format!(
+				"{} requires #[dissolve({})]",
+				DissolvedOption::DEFAULT_IDENT,
+				ContainerAttributes::RECOMPOSE_IDENT,
+			),
+		));
+	}
+
+	let dissolved_struct_name = format_ident!("{}Dissolved", struct_name);
+
+	reject_dissolved_name_collisions(&included_fields, "the dissolved struct")?;
+
+	// Groups are listed in the order they first appear, along with the fields nested in them
+	let mut groups: Vec<FieldGroup> = Vec::new();
+
+	for (index, (_, info)) in included_fields.iter().enumerate() {
+		let Some(group) = &info.group else {
+			continue;
+		};
+
+		if !container_attrs.nested {
+			return Err(Error::new_spanned(
+				group,
+				format!(
+					"{} requires #[dissolve({})]",
+					DissolvedOption::GROUP_IDENT,
+					ContainerAttributes::NESTED_IDENT,
+				),
+			));
+		}
+
+		// unwrap is safe because struct has named fields
+		let clashes = included_fields.iter().any(|(field, info)| {
+			info.group.is_none() && info.dissolved_name(field.ident.as_ref().unwrap()) == group
+		});
+
+		if clashes {
+			return Err(Error::new_spanned(
+				group,
+				format!(
+					"{} `{group}` clashes with a dissolved field of the same name",
+					DissolvedOption::GROUP_IDENT
+				),
+			));
+		}
+
+		match groups.iter_mut().find(|existing| existing.name == *group) {
+			Some(existing) => existing.members.push(index),
+			None => groups.push(FieldGroup {
+				name: group.clone(),
+				struct_name: format_ident!(
+					"{}{}Dissolved",
+					struct_name,
+					to_pascal_case(&group.to_string())
+				),
+				members: vec![index],
+			}),
+		}
+	}
+
+	let field_definitions: Vec<_> = included_fields
+		.iter()
+		.map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let ty = dissolved_field_type(&field.ty, container_attrs);
+
+			let dissolved_field_name = info.dissolved_name(original_name);
+
+			let kept_attrs: Vec<_> =
+				field.attrs.iter().filter(|attr| container_attrs.keeps_attr(attr)).collect();
+
+			let provenance_doc = container_attrs.doc_provenance.then(|| {
+				let mut notes = Vec::new();
+
+				if info.renamed_to.is_some() {
+					notes.push(format!("Renamed from `{}`.", original_name.unraw()));
+				}
+
+				match &field.vis {
+					syn::Visibility::Inherited => {
+						notes.push("Originally private field.".to_owned())
+					},
+					syn::Visibility::Restricted(restricted) => {
+						notes.push(format!(
+							"Originally `{}` field.",
+							restricted_visibility(restricted)
+						));
+					},
+					syn::Visibility::Public(_) => {},
+				}
+
+				let has_docs = kept_attrs.iter().any(|attr| attr.path().is_ident("doc"));
+
+				// Separate the notes from forwarded docs so they render as their own paragraph
+				let separator = (has_docs && !notes.is_empty()).then(|| quote! { #[doc = ""] });
+				let notes = notes.iter().map(|note| format!(" {note}"));
+
+				quote! {
+					#separator
+					#(#[doc = #notes])*
+				}
+			});
+
+			let field_vis = if container_attrs.inherit_field_vis {
+				field.vis.to_token_stream()
+			} else {
+				quote! { pub }
+			};
+
+			let kept_attrs = kept_attrs.iter().map(|attr| container_attrs.forward_attr(attr));
+
+			quote! {
+				#(#kept_attrs)*
+				#provenance_doc
+				#field_vis #dissolved_field_name: #ty
+			}
+		})
+		.collect();
+
+	let field_moves: Vec<_> = included_fields
+		.iter()
+		.map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+
+			// Destructured fields are bound to their dissolved name, so they can be used as-is
+			FieldMove {
+				member: original_member(field),
+				binding: info.dissolved_name(original_name).clone(),
+				with: info.with.clone(),
+			}
+		})
+		.collect();
+
+	let field_inits: Vec<_> = field_moves
+		.iter()
+		.map(|field_move| {
+			let dissolved_field_name = &field_move.binding;
+
+			if container_attrs.moves_by_binding() && field_move.with.is_none() {
+				quote! { #dissolved_field_name }
+			} else {
+				let moved_value = move_field(field_move, container_attrs);
+				quote! { #dissolved_field_name: #moved_value }
+			}
+		})
+		.collect();
+
+	let carrier_moves: Vec<_> = carried_fields
+		.iter()
+		.map(|(field, _)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+
+			FieldMove {
+				member: original_member(field),
+				binding: format_ident!("carrier_{}", original_name.unraw()),
+				with: None,
+			}
+		})
+		.collect();
+
+	let carrier_inits =
+		carried_fields.iter().zip(&carrier_moves).map(|((field, _), field_move)| {
+			let (name, member) = (&field.ident, &field_move.member);
+
+			// Carried fields keep their original type, so they are not wrapped in `ManuallyDrop`
+			let moved_value = if container_attrs.manually_drop {
+				quote! { ::core::ptr::read(&this.#member) }
+			} else {
+				move_field(field_move, container_attrs)
+			};
+
+			quote! { #name: #moved_value }
+		});
+
+	// Carried fields are moved into the carrier, so they must not be dropped
+	let skipped_members: Vec<_> = all_fields
+		.iter()
+		.filter(|(_, info)| info.should_skip && info.carried().is_none())
+		.map(|(field, _)| original_member(field))
+		.collect();
+
+	// Split generics for use in different positions
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	// Spell out what was left out, so readers of the dissolved type need not open the original
+	let skipped_names: Vec<_> = all_fields
+		.iter()
+		.filter(|(_, info)| info.should_skip)
+		.map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let name = format!("`{}`", field.ident.as_ref().unwrap().unraw());
+
+			match info.carried() {
+				Some(_) => format!("{name} (kept in the carrier returned next to this struct)"),
+				None => name,
+			}
+		})
+		.collect();
+	let skipped_note = if skipped_names.is_empty() {
+		String::new()
+	} else {
+		format!("\n\nSkipped fields: {}.", skipped_names.join(", "))
+	};
+
+	let method_link = container_attrs.method_doc_link(struct_name);
+	let dissolved_struct_doc = format!(
+		"Dissolved struct for [`{struct_name}`].\n\n\
+		This struct contains all non-skipped fields from the original struct. \
+		The visibility of this struct matches the visibility of the {method_link} method. \
+		Fields may be renamed according to `#[dissolved(rename = \"...\")]` attributes.\
+		{skipped_note}",
+	);
+	let dissolved_struct_doc = container_attrs.auto_doc(quote! { #[doc = #dissolved_struct_doc] });
 
 	let visibility = &container_attrs.visibility;
 
-	Ok(quote! {
-		#[doc = #dissolved_struct_doc]
-		#visibility struct #dissolved_struct_name #impl_generics #where_clause {
-			#(#field_definitions),*
+	let derives = container_attrs.derives_attr();
+
+	// An opaque struct is left out of the documentation, and other crates can read its fields but
+	// cannot build it themselves
+	let opaque = container_attrs.opaque.then(|| quote! { #[doc(hidden)] #[non_exhaustive] });
+
+	// The marker added for unused generic parameters is a ZST, so it is allowed next to the field
+	let repr = container_attrs.transparent.then(|| quote! { #[repr(transparent)] });
+
+	let dissolved_generics = if container_attrs.prune_generics {
+		prune_generics(generics, included_fields.iter().map(|(field, _)| &field.ty))
+	} else {
+		PrunedGenerics { kept: generics.clone(), pruned: Vec::new() }
+	};
+	let (dissolved_impl_generics, dissolved_ty_generics, dissolved_where_clause) =
+		dissolved_generics.kept.split_for_impl();
+
+	let marker_fields = |members: &[usize]| {
+		members
+			.iter()
+			.map(|&index| {
+				let (field, info) = included_fields[index];
+				// unwrap is safe because struct has named fields
+				(
+					*field,
+					info.dissolved_name(field.ident.as_ref().unwrap()).clone(),
+				)
+			})
+			.collect::<Vec<_>>()
+	};
+
+	// The field of a group mentions every parameter of the dissolved struct, so no marker is
+	// needed next to it
+	let marker = if groups.is_empty() {
+		GenericsMarker::new(
+			&dissolved_generics.kept,
+			&marker_fields(&(0..included_fields.len()).collect::<Vec<_>>()),
+		)
+	} else {
+		None
+	};
+	let marker_definition = marker.as_ref().map(|GenericsMarker { name, ty }| {
+		quote! {
+			/// Marks the generic parameters that are only used by skipped fields.
+			pub #name: #ty
+		}
+	});
+	let marker_init = marker.as_ref().map(|GenericsMarker { name, .. }| {
+		quote! { #name: ::core::marker::PhantomData }
+	});
+
+	// A private field keeps the struct from being built with a literal outside of its module
+	let seal = container_attrs.seal.then(|| {
+		let mut name = format_ident!("_seal");
+		while included_fields.iter().any(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let top_level_name = match &info.group {
+				Some(group) => group,
+				None => info.dissolved_name(field.ident.as_ref().unwrap()),
+			};
+			*top_level_name == name
+		}) || marker.as_ref().is_some_and(|marker| marker.name == name)
+		{
+			name = format_ident!("{}_", name);
+		}
+
+		name
+	});
+	let seal_definition = seal.as_ref().map(|name| {
+		quote! {
+			/// Keeps this struct from being built outside of `dissolve`.
+			#name: ()
+		}
+	});
+	let seal_init = seal.as_ref().map(|name| quote! { #name: () });
+
+	let group_markers: Vec<_> = groups
+		.iter()
+		.map(|group| GenericsMarker::new(&dissolved_generics.kept, &marker_fields(&group.members)))
+		.collect();
+
+	let group_definitions = groups.iter().zip(&group_markers).map(|(group, marker)| {
+		let FieldGroup { name, struct_name: group_struct_name, members } = group;
+		let group_fields = members.iter().map(|&index| &field_definitions[index]);
+		let group_marker_definition = marker.as_ref().map(|GenericsMarker { name, ty }| {
+			quote! {
+				/// Marks the generic parameters that are not used by the fields of this group.
+				pub #name: #ty
+			}
+		});
+
+		let group_doc = format!("Fields of [`{dissolved_struct_name}`] grouped under `{name}`.",);
+
+		quote! {
+			#[doc = #group_doc]
+			#opaque
+			#derives
+			#[allow(clippy::struct_excessive_bools)]
+			#allow_non_snake_case
+			#visibility struct #group_struct_name #dissolved_impl_generics #dissolved_where_clause {
+				#(#group_fields,)*
+				#group_marker_definition
+			}
+		}
+	});
+
+	let top_field_definitions = nest_groups(field_definitions.clone(), &groups, |index, _| {
+		let FieldGroup { name, struct_name: group_struct_name, .. } = &groups[index];
+
+		quote! { pub #name: #group_struct_name #dissolved_ty_generics }
+	});
+
+	// Builds the dissolved struct from the initializers of its included fields, in declaration
+	// order, nesting the grouped ones
+	let construct = |inits: Vec<proc_macro2::TokenStream>| {
+		let inits = nest_groups(inits, &groups, |index, inits| {
+			let FieldGroup { name, struct_name: group_struct_name, .. } = &groups[index];
+			let group_marker_init =
+				group_markers[index].as_ref().map(|GenericsMarker { name, .. }| {
+					quote! { #name: ::core::marker::PhantomData }
+				});
+
+			quote! {
+				#name: #group_struct_name {
+					#(#inits,)*
+					#group_marker_init
+				}
+			}
+		});
+
+		quote! {
+			#dissolved_struct_name {
+				#(#inits,)*
+				#marker_init
+				#seal_init
+			}
+		}
+	};
+
+	// Path of an included field from the dissolved struct, through its group if it has one
+	let dissolved_path = |info: &FieldInfo, dissolved_field_name: &syn::Ident| match &info.group {
+		Some(group) => quote! { #group.#dissolved_field_name },
+		None => dissolved_field_name.to_token_stream(),
+	};
+
+	let dissolved_ty = quote! { #dissolved_struct_name #dissolved_ty_generics };
+
+	// With `boxed`, the dissolved struct is returned on the heap, everywhere it is returned
+	let returned_dissolved_ty = if container_attrs.boxed {
+		quote! { ::std::boxed::Box<#dissolved_ty> }
+	} else {
+		dissolved_ty.clone()
+	};
+	let return_dissolved = |construction: proc_macro2::TokenStream| {
+		if container_attrs.boxed {
+			quote! { ::std::boxed::Box::new(#construction) }
+		} else {
+			construction
+		}
+	};
+
+	let carrier_name = format_ident!("{}Carrier", struct_name);
+
+	let carrier = (!carried_fields.is_empty()).then(|| {
+		// Fields kept for rebuild stay private, fields marked `keep` are handed back to the caller
+		let carrier_fields = carried_fields.iter().map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let ty = &field.ty;
+			let field_vis = match (&info.keep, container_attrs.inherit_field_vis) {
+				(None, _) => None,
+				(Some(_), true) => Some(field.vis.to_token_stream()),
+				(Some(_), false) => Some(quote! { pub }),
+			};
+
+			quote! { #field_vis #original_name: #ty }
+		});
+
+		let carrier_marker = GenericsMarker::new(
+			generics,
+			&carried_fields
+				.iter()
+				.map(|(field, _)| {
+					// unwrap is safe because struct has named fields
+					(*field, field.ident.clone().unwrap())
+				})
+				.collect::<Vec<_>>(),
+		);
+		let carrier_marker_definition =
+			carrier_marker.as_ref().map(|GenericsMarker { name, ty }| quote! { #name: #ty });
+		let carrier_marker_init = carrier_marker.as_ref().map(|GenericsMarker { name, .. }| {
+			quote! { #name: ::core::marker::PhantomData }
+		});
+
+		let carrier_doc = if carried_fields.iter().all(|(_, info)| info.keep.is_none()) {
+			format!(
+				"Skipped fields of [`{struct_name}`] that are kept to rebuild it.\n\n\
+				Returned by `dissolve` next to [`{dissolved_struct_name}`]. Its fields are private, \
+				so it can only be passed back to [`{dissolved_struct_name}::into_original`].",
+			)
+		} else {
+			let keep_vis = if container_attrs.inherit_field_vis {
+				"keep the visibility they have on the original struct"
+			} else {
+				"are public"
+			};
+			format!(
+				"Skipped fields of [`{struct_name}`] that are kept rather than dropped.\n\n\
+				Returned by `dissolve` next to [`{dissolved_struct_name}`]. Fields marked `keep` \
+				{keep_vis}, while fields kept for rebuild are private.",
+			)
+		};
+
+		let definition = quote! {
+			#[doc = #carrier_doc]
+			#visibility struct #carrier_name #impl_generics #where_clause {
+				#(#carrier_fields,)*
+				#carrier_marker_definition
+			}
+		};
+
+		let construction = quote! {
+			#carrier_name {
+				#(#carrier_inits,)*
+				#carrier_marker_init
+			}
+		};
+
+		(definition, construction)
+	});
+	let (carrier_definition, carrier_construction) = carrier.unzip();
+
+	let dissolve_output_ty = if carrier_construction.is_some() {
+		quote! { (#returned_dissolved_ty, #carrier_name #ty_generics) }
+	} else {
+		returned_dissolved_ty.clone()
+	};
+
+	let recompose_impl = container_attrs.recompose.then(|| {
+		let carrier_param = carrier_definition.is_some().then(|| {
+			quote! { carrier: #carrier_name #ty_generics, }
+		});
+
+		let skipped_params: Vec<_> = all_fields
+			.iter()
+			.filter(|(_, info)| {
+				info.should_skip && info.carried().is_none() && info.default.is_none()
+			})
+			.map(|(field, info)| {
+				// unwrap is safe because struct has named fields
+				let param_name = info.dissolved_name(field.ident.as_ref().unwrap());
+				let ty = &field.ty;
+
+				quote! { #param_name: #ty }
+			})
+			.collect();
+
+		// Every field of the original struct must come from exactly one of the dissolved struct,
+		// the carrier, the arguments or its default, which is checked when the generated code is
+		// compiled
+		let (dissolved_count, carried_count, param_count, default_count, original_count) = (
+			included_fields.len(),
+			carried_fields.len(),
+			skipped_params.len(),
+			all_fields.iter().filter(|(_, info)| info.default.is_some()).count(),
+			all_fields.len(),
+		);
+		let arity_check = quote! {
+			const _: () = ::core::assert!(
+				#dissolved_count + #carried_count + #param_count + #default_count == #original_count,
+				"dissolve-derive generated an `into_original` that does not rebuild every field",
+			);
+		};
+
+		let original_field_inits = all_fields.iter().map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let member = original_member(field);
+
+			if info.carried().is_some() {
+				quote! { #member: carrier.#original_name }
+			} else if info.default.is_some() {
+				match &info.default_value {
+					Some(value) => quote! { #member: #value },
+					None => quote! { #member: ::core::default::Default::default() },
+				}
+			} else if info.should_skip {
+				let param_name = info.dissolved_name(original_name);
+
+				if member == syn::Member::Named(param_name.clone()) {
+					quote! { #member }
+				} else {
+					quote! { #member: #param_name }
+				}
+			} else {
+				let dissolved_field_path = dissolved_path(info, info.dissolved_name(original_name));
+
+				if container_attrs.manually_drop {
+					quote! {
+						#member: ::core::mem::ManuallyDrop::into_inner(self.#dissolved_field_path)
+					}
+				} else {
+					quote! { #member: self.#dissolved_field_path }
+				}
+			}
+		});
+
+		// Pruned parameters only appear in skipped fields, so they become parameters of the
+		// method, which also has to restate every bound the original struct requires
+		let (method_generics, method_where_clause) = if dissolved_generics.pruned.is_empty() {
+			(None, None)
+		} else {
+			let pruned_params = dissolved_generics.pruned.iter().map(|param| match param {
+				syn::GenericParam::Type(type_param) => type_param.ident.to_token_stream(),
+				syn::GenericParam::Lifetime(lifetime_param) => {
+					lifetime_param.lifetime.to_token_stream()
+				},
+				syn::GenericParam::Const(const_param) => {
+					let (ident, ty) = (&const_param.ident, &const_param.ty);
+					quote! { const #ident: #ty }
+				},
+			});
+
+			(
+				Some(quote! { <#(#pruned_params),*> }),
+				Some(all_generic_bounds(generics, &dissolved_generics.pruned)),
+			)
+		};
+
+		quote! {
+			#arity_check
+
+			impl #dissolved_impl_generics #dissolved_struct_name #dissolved_ty_generics #dissolved_where_clause {
+				/// Recompose the original struct from its dissolved parts.
+				///
+				/// Skipped fields are not part of the dissolved struct, so their values must be
+				/// supplied as arguments, in the order they are declared in the original struct.
+				/// Fields marked `keep_for_rebuild` or `keep` are taken from the carrier returned by
+				/// `dissolve` instead, and fields marked `default` are set to their default value.
+				#[allow(clippy::too_many_arguments)]
+				#allow_non_snake_case
+				#visibility fn into_original #method_generics (
+					self,
+					#carrier_param
+					#(#skipped_params),*
+				) -> #struct_name #ty_generics
+				#method_where_clause
+				{
+					#struct_name {
+						#(#original_field_inits),*
+					}
+				}
+			}
+		}
+	});
+
+	let from_dissolved_impl = container_attrs.from_dissolved.then(|| {
+		let original_field_inits = included_fields.iter().map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+			let dissolved_field_path = dissolved_path(info, dissolved_field_name);
+			let member = original_member(field);
+
+			if container_attrs.manually_drop {
+				quote! {
+					#member: ::core::mem::ManuallyDrop::into_inner(dissolved.#dissolved_field_path)
+				}
+			} else {
+				quote! { #member: dissolved.#dissolved_field_path }
+			}
+		});
+
+		quote! {
+			impl #dissolved_impl_generics ::core::convert::From<#dissolved_struct_name #dissolved_ty_generics>
+				for #struct_name #ty_generics
+			#dissolved_where_clause
+			{
+				fn from(dissolved: #dissolved_struct_name #dissolved_ty_generics) -> Self {
+					#struct_name {
+						#(#original_field_inits),*
+					}
+				}
+			}
+		}
+	});
+
+	let aliases: Vec<_> = included_fields
+		.iter()
+		.filter_map(|(field, info)| {
+			let alias = info.alias.as_ref()?;
+			// unwrap is safe because struct has named fields
+			let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+			let dissolved_field_path = dissolved_path(info, dissolved_field_name);
+			let ty = dissolved_field_type(&field.ty, container_attrs);
+
+			let note = format!("use the `{}` field instead", dissolved_field_name.unraw());
+			let doc = format!(
+				" Borrow the `{}` field under its former name `{}`.",
+				dissolved_field_name.unraw(),
+				alias.unraw()
+			);
+
+			Some(quote! {
+				#[doc = #doc]
+				#[deprecated(note = #note)]
+				#visibility fn #alias(&self) -> &#ty {
+					&self.#dissolved_field_path
+				}
+			})
+		})
+		.collect();
+	let aliases_impl = (!aliases.is_empty()).then(|| {
+		quote! {
+			impl #dissolved_impl_generics #dissolved_struct_name #dissolved_ty_generics #dissolved_where_clause {
+				#(#aliases)*
+			}
+		}
+	});
+
+	let field_extractors_impl = if container_attrs.field_extractors {
+		let extractors = included_fields
+			.iter()
+			.map(|(field, info)| {
+				// unwrap is safe because struct has named fields
+				let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+				let method_name = format_ident!(
+					"into_{}",
+					dissolved_field_name.unraw(),
+					span = dissolved_field_name.span()
+				);
+				let ty = dissolved_field_type(&field.ty, container_attrs);
+
+				if container_attrs.recompose && method_name == "into_original" {
+					return Err(Error::new_spanned(
+						field,
+						format!(
+							"{} would generate `into_original` for this field, which clashes with {}",
+							ContainerAttributes::FIELD_EXTRACTORS_IDENT,
+							ContainerAttributes::RECOMPOSE_IDENT,
+						),
+					));
+				}
+
+				let doc = format!(
+					" Extract the `{}` field, dropping the other fields.",
+					dissolved_field_name.unraw()
+				);
+				let dissolved_field_path = dissolved_path(info, dissolved_field_name);
+
+				Ok(quote! {
+					#[doc = #doc]
+					#visibility fn #method_name(self) -> #ty {
+						self.#dissolved_field_path
+					}
+				})
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Some(quote! {
+			#allow_non_snake_case
+			impl #dissolved_impl_generics #dissolved_struct_name #dissolved_ty_generics #dissolved_where_clause {
+				#(#extractors)*
+			}
+		})
+	} else {
+		None
+	};
+
+	let test_helpers_impl = container_attrs.test_helpers.then(|| {
+		let params = included_fields.iter().map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+			let ty = dissolved_field_type(&field.ty, container_attrs);
+
+			quote! { #dissolved_field_name: #ty }
+		});
+
+		let field_names = included_fields
+			.iter()
+			.map(|(field, info)| {
+				// unwrap is safe because struct has named fields
+				info.dissolved_name(field.ident.as_ref().unwrap()).to_token_stream()
+			})
+			.collect();
+		let construction = construct(field_names);
+
+		quote! {
+			impl #dissolved_impl_generics #dissolved_struct_name #dissolved_ty_generics #dissolved_where_clause {
+				/// Create the dissolved struct directly from its fields, in declaration order.
+				#[allow(clippy::too_many_arguments)]
+				#allow_non_snake_case
+				#visibility fn new(#(#params),*) -> Self {
+					#construction
+				}
+			}
+		}
+	});
+
+	let into_tuple_impl = container_attrs.into_tuple.then(|| {
+		let tuple_types = included_fields
+			.iter()
+			.map(|(field, _)| dissolved_field_type(&field.ty, container_attrs));
+		let tuple_values = included_fields.iter().map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+			let dissolved_field_path = dissolved_path(info, dissolved_field_name);
+
+			quote! { dissolved.#dissolved_field_path }
+		});
+
+		quote! {
+			impl #dissolved_impl_generics ::core::convert::From<#dissolved_struct_name #dissolved_ty_generics>
+				for (#(#tuple_types,)*)
+			#dissolved_where_clause
+			{
+				fn from(dissolved: #dissolved_struct_name #dissolved_ty_generics) -> Self {
+					(#(#tuple_values,)*)
+				}
+			}
 		}
+	});
+
+	let tuple_from_impl = container_attrs.tuple_from.then(|| {
+		let tuple_types = included_fields
+			.iter()
+			.map(|(field, _)| dissolved_field_type(&field.ty, container_attrs));
+		let tuple_values = included_fields.iter().map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+			let dissolved_field_path = dissolved_path(info, dissolved_field_name);
+
+			quote! { dissolved.#dissolved_field_path }
+		});
+
+		let positions = included_fields.iter().enumerate().map(|(index, (field, info))| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let dissolved_field_name = info.dissolved_name(original_name);
+
+			if info.renamed_to.is_some() {
+				format!(
+					" - `{index}`: `{}`, renamed from `{}`",
+					dissolved_field_name.unraw(),
+					original_name.unraw()
+				)
+			} else {
+				format!(" - `{index}`: `{}`", dissolved_field_name.unraw())
+			}
+		});
+		let impl_doc = format!(
+			" Dissolve [`{struct_name}`] into a tuple of its included fields, in declaration order:",
+		);
+
+		let dissolved = bind_dissolved(
+			quote! { value },
+			carrier_construction.is_some(),
+			container_attrs,
+		);
 
-		impl #impl_generics #struct_name #ty_generics #where_clause {
-			/// Dissolve this struct into its public-field equivalent.
+		quote! {
+			#[doc = #impl_doc]
 			///
-			/// This method consumes the original struct and returns a new struct where all included
-			/// fields are made public and optionally renamed.
-			#visibility fn dissolve(self) -> #dissolved_struct_name #ty_generics {
-				#dissolved_struct_name {
-					#(#field_moves),*
+			#(#[doc = #positions])*
+			impl #impl_generics ::core::convert::From<#struct_name #ty_generics>
+				for (#(#tuple_types,)*)
+			#where_clause
+			{
+				fn from(value: #struct_name #ty_generics) -> Self {
+					#dissolved
+					(#(#tuple_values,)*)
 				}
 			}
 		}
+	});
+
+	let map_dissolved = map_dissolved_method(&dissolve_output_ty, generics, container_attrs);
+
+	let to_json = to_json_method(carrier_construction.is_some(), container_attrs);
+
+	let transition = match (&container_attrs.transition_to, &container_attrs.via) {
+		(Some(target), Some(via)) => Some(transition_method(
+			target,
+			via,
+			&all_fields,
+			carrier_construction.is_some(),
+			container_attrs,
+		)?),
+		_ => None,
+	};
+
+	let dynamic = dynamic_method(
+		&included_fields
+			.iter()
+			.map(|(field, info)| {
+				// unwrap is safe because struct has named fields
+				let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+
+				(
+					dissolved_field_name.unraw().to_string(),
+					dissolved_path(info, dissolved_field_name),
+					&field.ty,
+				)
+			})
+			.collect::<Vec<_>>(),
+		carrier_construction.is_some(),
+		generics,
+		container_attrs,
+	);
+
+	let tagged = tagged_items(
+		struct_name,
+		&included_fields
+			.iter()
+			.map(|(field, info)| {
+				// unwrap is safe because struct has named fields
+				let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+
+				(
+					dissolved_field_name,
+					dissolved_path(info, dissolved_field_name),
+					&field.ty,
+				)
+			})
+			.collect::<Vec<_>>(),
+		carrier_construction.is_some(),
+		generics,
+		container_attrs,
+	)?;
+	let (tagged_definition, tagged_method) = tagged.unzip();
+
+	let snapshot_inits = included_fields
+		.iter()
+		.map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let dissolved_field_name = info.dissolved_name(original_name);
+			let cloned_value = cloned_field(&original_member(field), container_attrs);
+
+			quote! { #dissolved_field_name: #cloned_value }
+		})
+		.collect();
+	let snapshot = snapshot_method(
+		&returned_dissolved_ty,
+		return_dissolved(construct(snapshot_inits)),
+		included_fields.iter().map(|(field, _)| &field.ty),
+		generics,
+		container_attrs,
+	);
+
+	let copy_view_inits = included_fields
+		.iter()
+		.map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let dissolved_field_name = info.dissolved_name(original_name);
+			let copied_value = copied_field(&original_member(field), container_attrs);
+
+			quote! { #dissolved_field_name: #copied_value }
+		})
+		.collect();
+	let copy_view = copy_view_method(
+		&returned_dissolved_ty,
+		return_dissolved(construct(copy_view_inits)),
+		included_fields.iter().map(|(field, _)| &field.ty),
+		generics,
+		container_attrs,
+	);
+
+	let drain_inits = included_fields
+		.iter()
+		.map(|(field, info)| {
+			// unwrap is safe because struct has named fields
+			let original_name = field.ident.as_ref().unwrap();
+			let dissolved_field_name = info.dissolved_name(original_name);
+			let drained_value = drained_field(&original_member(field), container_attrs);
+
+			quote! { #dissolved_field_name: #drained_value }
+		})
+		.collect();
+	let drain = drain_method(
+		&returned_dissolved_ty,
+		return_dissolved(construct(drain_inits)),
+		included_fields.iter().map(|(field, _)| &field.ty),
+		generics,
+		container_attrs,
+	);
+
+	let dissolved_construction = return_dissolved(construct(field_inits));
+
+	let dissolve_body = dissolve_body(
+		match &carrier_construction {
+			Some(carrier_construction) => {
+				quote! { (#dissolved_construction, #carrier_construction) }
+			},
+			None => dissolved_construction,
+		},
+		&field_moves.into_iter().chain(carrier_moves).collect::<Vec<_>>(),
+		&skipped_members,
+		container_attrs,
+	);
+
+	let dissolve_doc = format!(
+		" Dissolve this struct into its public-field equivalent.\n\n \
+		This method consumes the original struct and returns a new {} where all included fields \
+		are made public and optionally renamed.",
+		container_attrs.dissolved_doc_link(&dissolved_struct_name),
+	);
+
+	let dissolve_doc = container_attrs.auto_doc(quote! { #[doc = #dissolve_doc] });
+	let dissolve_item = dissolve_item(
+		struct_name,
+		generics,
+		quote! {
+			#dissolve_doc
+			#allow_non_snake_case
+		},
+		&dissolve_output_ty,
+		dissolve_body,
+		quote! {
+			#map_dissolved #snapshot #copy_view #drain #dynamic #tagged_method #to_json #transition
+		},
+		container_attrs,
+	);
+
+	Ok(quote! {
+		#dissolved_struct_doc
+		#opaque
+		#derives
+		#[allow(clippy::struct_excessive_bools)]
+		#allow_non_snake_case
+		#repr
+		#visibility struct #dissolved_struct_name #dissolved_impl_generics #dissolved_where_clause {
+			#(#top_field_definitions,)*
+			#marker_definition
+			#seal_definition
+		}
+
+		#(#group_definitions)*
+
+		#carrier_definition
+
+		#tagged_definition
+
+		#dissolve_item
+
+		#recompose_impl
+
+		#into_tuple_impl
+
+		#from_dissolved_impl
+
+		#tuple_from_impl
+
+		#test_helpers_impl
+
+		#field_extractors_impl
+
+		#aliases_impl
+
+		#as_ref_impl
 	})
 }
 
+/// Generates the `dissolve` method and its impls for a tuple struct, which returns the included
+/// fields as a tuple, or as an array with `array`.
+///
+/// `original_members` holds the member of each field in the struct being dissolved, which is a
+/// field name for a named struct dissolved with `newtype`.
 fn generate_tuple_struct_impl(
 	struct_name: &syn::Ident,
 	generics: &syn::Generics,
 	fields: &FieldsUnnamed,
+	original_members: &[syn::Member],
 	container_attrs: &ContainerAttributes,
 ) -> Result<proc_macro2::TokenStream> {
+	let named_newtype = matches!(original_members.first(), Some(syn::Member::Named(_)));
+
+	let named_only_options = [
+		(
+			container_attrs.recompose,
+			ContainerAttributes::RECOMPOSE_IDENT,
+		),
+		(
+			container_attrs.into_tuple,
+			ContainerAttributes::INTO_TUPLE_IDENT,
+		),
+		(
+			container_attrs.from_dissolved,
+			ContainerAttributes::FROM_DISSOLVED_IDENT,
+		),
+		(
+			container_attrs.transition_to.is_some(),
+			ContainerAttributes::TRANSITION_TO_IDENT,
+		),
+		(container_attrs.seal, ContainerAttributes::SEAL_IDENT),
+		(
+			container_attrs.doc_provenance,
+			ContainerAttributes::DOC_PROVENANCE_IDENT,
+		),
+		(
+			container_attrs.prune_generics,
+			ContainerAttributes::PRUNE_GENERICS_IDENT,
+		),
+		(
+			container_attrs.inherit_field_vis,
+			ContainerAttributes::INHERIT_FIELD_VIS_IDENT,
+		),
+		(
+			container_attrs.test_helpers,
+			ContainerAttributes::TEST_HELPERS_IDENT,
+		),
+		(
+			container_attrs.only.is_some(),
+			ContainerAttributes::ONLY_IDENT,
+		),
+		(
+			container_attrs.only_public.is_some(),
+			ContainerAttributes::ONLY_PUBLIC_IDENT,
+		),
+		(
+			container_attrs.keep_attrs.is_some(),
+			ContainerAttributes::KEEP_ATTRS_IDENT,
+		),
+		(container_attrs.boxed, ContainerAttributes::BOXED_IDENT),
+		(
+			container_attrs.transparent,
+			ContainerAttributes::TRANSPARENT_IDENT,
+		),
+		(
+			container_attrs.derives.is_some(),
+			ContainerAttributes::DERIVE_IDENT,
+		),
+		(container_attrs.serde, ContainerAttributes::SERDE_IDENT),
+		(
+			container_attrs.field_extractors,
+			ContainerAttributes::FIELD_EXTRACTORS_IDENT,
+		),
+		(container_attrs.nested, ContainerAttributes::NESTED_IDENT),
+		(container_attrs.opaque, ContainerAttributes::OPAQUE_IDENT),
+		(container_attrs.dynamic, ContainerAttributes::DYNAMIC_IDENT),
+		(container_attrs.tagged, ContainerAttributes::TAGGED_IDENT),
+		(
+			container_attrs.tuple_from,
+			ContainerAttributes::TUPLE_FROM_IDENT,
+		),
+	];
+
+	if let Some((_, option)) = named_only_options.iter().find(|(enabled, _)| *enabled) {
+		let message = if named_newtype {
+			format!(
+				"{option} cannot be combined with {}, which dissolves into the value of the field",
+				ContainerAttributes::NEWTYPE_IDENT,
+			)
+		} else {
+			format!("{option} is only supported for named structs")
+		};
+
+		return Err(Error::new_spanned(struct_name, message));
+	}
+
 	// For tuple structs, only `skip` is supported (`rename` does not make sense)
-	let included_fields: Vec<_> = fields
+	let all_fields = fields
 		.unnamed
 		.iter()
 		.enumerate()
-		.filter_map(|(index, field)| {
-			match get_field_info(field) {
-				Ok(info) => {
-					if info.should_skip {
-						None
-					} else {
-						// Check if rename was attempted on tuple struct
-						if info.renamed_to.is_some() {
-							Some(Err(Error::new_spanned(
-								field,
-								format!(
-									"{} is unsupported for tuple struct fields, only {} is allowed",
-									DissolvedOption::RENAME_IDENT,
-									DissolvedOption::SKIP_IDENT,
-								),
-							)))
-						} else {
-							Some(Ok((index, field)))
-						}
-					}
-				},
-				Err(err) => Some(Err(err)),
+		.map(|(index, field)| {
+			let fields_kind = if named_newtype {
+				"newtype"
+			} else {
+				"tuple struct"
+			};
+			let info = get_field_info(field, container_attrs)?;
+			info.reject_named_only_options(fields_kind)?;
+
+			// Check if rename was attempted on tuple struct
+			if info.renamed_to.is_some() {
+				return Err(Error::new_spanned(
+					field,
+					format!(
+						"{} is unsupported for {fields_kind} fields, only {} is allowed",
+						DissolvedOption::RENAME_IDENT,
+						DissolvedOption::SKIP_IDENT,
+					),
+				));
 			}
+
+			Ok((index, field, info))
 		})
-		.collect::<Result<_>>()?;
+		.collect::<Result<Vec<_>>>()?;
+
+	let included_fields: Vec<_> = all_fields
+		.iter()
+		.filter(|(_, _, info)| !info.should_skip)
+		.map(|(index, field, _)| (*index, *field))
+		.collect();
 
 	if included_fields.is_empty() {
 		return Err(Error::new_spanned(
@@ -592,46 +5154,343 @@ fn generate_tuple_struct_impl(
 		));
 	}
 
-	let tuple_types = included_fields.iter().map(|(_, field)| &field.ty);
-	let tuple_type = if included_fields.len() == 1 {
-		// Single element tuple needs trailing comma
-		let ty = &included_fields[0].1.ty;
-		quote! { (#ty,) }
-	} else {
-		quote! { (#(#tuple_types),*) }
+	let as_ref_impl = as_ref_impl(
+		struct_name,
+		generics,
+		&included_fields
+			.iter()
+			.map(|(index, field)| (original_members[*index].clone(), &field.ty))
+			.collect::<Vec<_>>(),
+		container_attrs,
+	)?;
+
+	if container_attrs.newtype && included_fields.len() > 1 {
+		return Err(Error::new_spanned(
+			struct_name,
+			format!(
+				"{} requires exactly one included field, found {}",
+				ContainerAttributes::NEWTYPE_IDENT,
+				included_fields.len(),
+			),
+		));
+	}
+
+	if container_attrs.array {
+		if container_attrs.newtype {
+			return Err(Error::new_spanned(
+				struct_name,
+				format!(
+					"{} cannot be combined with {}",
+					ContainerAttributes::ARRAY_IDENT,
+					ContainerAttributes::NEWTYPE_IDENT,
+				),
+			));
+		}
+
+		let (_, first_field) = included_fields[0];
+
+		if let Some((_, field)) =
+			included_fields.iter().find(|(_, field)| field.ty != first_field.ty)
+		{
+			let (expected, found) = (&first_field.ty, &field.ty);
+
+			return Err(Error::new_spanned(
+				found,
+				format!(
+					"{} requires all included fields to have the same type, expected `{}`, found `{}`",
+					ContainerAttributes::ARRAY_IDENT,
+					quote! { #expected },
+					quote! { #found },
+				),
+			));
+		}
+	}
+
+	let tuple_types =
+		included_fields.iter().map(|(_, field)| dissolved_field_type(&field.ty, container_attrs));
+	let tuple_type = if container_attrs.newtype {
+		// Newtypes dissolve into their inner value rather than a single element tuple
+		quote! { #(#tuple_types)* }
+	} else if container_attrs.array {
+		let element_type = tuple_types.take(1);
+		let len = included_fields.len();
+
+		quote! { [#(#element_type)*; #len] }
+	} else if included_fields.len() == 1 {
+		// Single element tuple needs trailing comma
+		quote! { (#(#tuple_types,)*) }
+	} else {
+		quote! { (#(#tuple_types),*) }
+	};
+
+	let field_moves: Vec<_> = all_fields
+		.iter()
+		.filter(|(_, _, info)| !info.should_skip)
+		.map(|(original_index, _, info)| {
+			let member = original_members[*original_index].clone();
+			let binding = match &member {
+				syn::Member::Named(ident) => ident.clone(),
+				syn::Member::Unnamed(_) => format_ident!("field_{}", original_index),
+			};
+
+			FieldMove { member, binding, with: info.with.clone() }
+		})
+		.collect();
+
+	let construct = |values: Vec<proc_macro2::TokenStream>| {
+		if container_attrs.newtype {
+			quote! { #(#values)* }
+		} else if container_attrs.array {
+			quote! { [#(#values),*] }
+		} else if included_fields.len() == 1 {
+			// Single element tuple needs trailing comma
+			quote! { (#(#values,)*) }
+		} else {
+			quote! { (#(#values),*) }
+		}
+	};
+
+	let tuple_construction = construct(
+		field_moves.iter().map(|field_move| move_field(field_move, container_attrs)).collect(),
+	);
+	let snapshot_construction = construct(
+		field_moves
+			.iter()
+			.map(|field_move| cloned_field(&field_move.member, container_attrs))
+			.collect(),
+	);
+	let copy_view_construction = construct(
+		field_moves
+			.iter()
+			.map(|field_move| copied_field(&field_move.member, container_attrs))
+			.collect(),
+	);
+	let drain_construction = construct(
+		field_moves
+			.iter()
+			.map(|field_move| drained_field(&field_move.member, container_attrs))
+			.collect(),
+	);
+
+	let skipped_members: Vec<_> = all_fields
+		.iter()
+		.filter(|(_, _, info)| info.should_skip)
+		.map(|(index, _, _)| original_members[*index].clone())
+		.collect();
+
+	let dissolve_body = dissolve_body(
+		tuple_construction,
+		&field_moves,
+		&skipped_members,
+		container_attrs,
+	);
+
+	// Split generics for use in different positions
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let map_dissolved = map_dissolved_method(&tuple_type, generics, container_attrs);
+	let snapshot = snapshot_method(
+		&tuple_type,
+		snapshot_construction,
+		included_fields.iter().map(|(_, field)| &field.ty),
+		generics,
+		container_attrs,
+	);
+	let copy_view = copy_view_method(
+		&tuple_type,
+		copy_view_construction,
+		included_fields.iter().map(|(_, field)| &field.ty),
+		generics,
+		container_attrs,
+	);
+	let drain = drain_method(
+		&tuple_type,
+		drain_construction,
+		included_fields.iter().map(|(_, field)| &field.ty),
+		generics,
+		container_attrs,
+	);
+
+	// A `From` impl for a bare type parameter (or a reference to one) would break the orphan rules,
+	// and so would one for a foreign type, which has no `dissolve` method to call anyway
+	let newtype_from_impl = (container_attrs.newtype
+		&& !container_attrs.remote
+		&& !included_fields.iter().any(|(_, field)| is_uncovered_type_param(&field.ty, generics)))
+	.then(|| {
+		let method_name = container_attrs.method_name();
+
+		quote! {
+			impl #impl_generics ::core::convert::From<#struct_name #ty_generics> for #tuple_type
+			#where_clause
+			{
+				fn from(value: #struct_name #ty_generics) -> Self {
+					value.#method_name()
+				}
+			}
+		}
+	});
+
+	let summary = if container_attrs.newtype {
+		"Dissolve this newtype into its inner value."
+	} else if container_attrs.array {
+		"Dissolve this tuple struct into an array of its included non-skipped fields."
+	} else {
+		"Dissolve this tuple struct into a tuple of its included non-skipped fields."
+	};
+
+	// Skipped fields shift the position of every later field, so spell out where each one ends up
+	let dissolve_doc = if skipped_members.is_empty() {
+		summary.to_owned()
+	} else {
+		let included = included_fields.iter().map(|(index, _)| {
+			let member = &original_members[*index];
+			format!("self.{}", quote! { #member })
+		});
+		let included = included.collect::<Vec<_>>().join(", ");
+
+		let returned = if container_attrs.newtype {
+			included
+		} else if container_attrs.array {
+			format!("[{included}]")
+		} else if included_fields.len() == 1 {
+			format!("({included},)")
+		} else {
+			format!("({included})")
+		};
+
+		let skipped = skipped_members
+			.iter()
+			.map(|member| format!("`self.{}`", quote! { #member }))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		format!("{summary}\n\nReturns `{returned}`, skipping {skipped}.")
 	};
+	let dissolve_doc = container_attrs.auto_doc(quote! { #[doc = #dissolve_doc] });
 
-	let field_moves = included_fields.iter().map(|(original_index, _)| {
-		let index = Index::from(*original_index);
-		quote! { self.#index }
-	});
+	let to_json = to_json_method(false, container_attrs);
 
-	let tuple_construction = if included_fields.len() == 1 {
-		// Single element tuple needs trailing comma
-		quote! { (#(#field_moves,)*) }
-	} else {
-		quote! { (#(#field_moves),*) }
-	};
+	// Long tuples of field types easily exceed clippy's type complexity threshold
+	let type_complexity_allow =
+		(!container_attrs.newtype && !container_attrs.array && included_fields.len() >= 3)
+			.then(|| quote! { #[allow(clippy::type_complexity)] });
 
-	// Split generics for use in different positions
-	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-	let visibility = &container_attrs.visibility;
+	let dissolve_item = dissolve_item(
+		struct_name,
+		generics,
+		quote! {
+			#dissolve_doc
+			#type_complexity_allow
+		},
+		&tuple_type,
+		dissolve_body,
+		quote! { #map_dissolved #snapshot #copy_view #drain #to_json },
+		container_attrs,
+	);
 
 	Ok(quote! {
-		impl #impl_generics #struct_name #ty_generics #where_clause {
-			/// Dissolve this tuple struct into a tuple of its included non-skipped fields.
-			#visibility fn dissolve(self) -> #tuple_type {
-				#tuple_construction
-			}
-		}
+		#dissolve_item
+
+		#newtype_from_impl
+
+		#as_ref_impl
 	})
 }
 
-fn get_field_info(field: &Field) -> Result<FieldInfo> {
-	let mut field_info = FieldInfo::new();
+/// Field name listed in `only(...)`, accepting both `"type"` and `"r#type"` for raw identifiers.
+fn only_field_name(listed: &syn::LitStr) -> String {
+	let value = listed.value();
+
+	match value.strip_prefix("r#") {
+		Some(unraw) => unraw.to_owned(),
+		None => value,
+	}
+}
+
+/// Whether a named field is included by the container's `only(...)` allowlist or `only_public`,
+/// which include every field when they are not set.
+fn selected_by_container(field: &Field, container_attrs: &ContainerAttributes) -> bool {
+	if let Some(only) = &container_attrs.only {
+		// unwrap is safe because only named structs accept `only`
+		let name = field.ident.as_ref().unwrap().unraw();
+		only.iter().any(|listed| name == only_field_name(listed))
+	} else if container_attrs.only_public.is_some() {
+		!matches!(field.vis, syn::Visibility::Inherited)
+	} else {
+		true
+	}
+}
+
+/// Skips a named field that is not listed in the container's `only(...)` allowlist, or that is
+/// private with `only_public`.
+fn apply_container_selection(
+	field: &Field,
+	info: &mut FieldInfo,
+	container_attrs: &ContainerAttributes,
+) -> Result<()> {
+	// unwrap is safe because struct has named fields
+	let name = field.ident.as_ref().unwrap().unraw();
+	let is_listed = selected_by_container(field, container_attrs);
+
+	if container_attrs.only_public.is_some() {
+		if !is_listed && info.renamed_to.is_some() {
+			return Err(Error::new_spanned(
+				field,
+				format!(
+					"cannot use {} on private field `{name}`, which {} skips",
+					DissolvedOption::RENAME_IDENT,
+					ContainerAttributes::ONLY_PUBLIC_IDENT,
+				),
+			));
+		}
+
+		info.should_skip |= !is_listed;
+
+		return Ok(());
+	}
+
+	if container_attrs.only.is_none() {
+		return Ok(());
+	}
+
+	if is_listed && info.should_skip {
+		return Err(Error::new_spanned(
+			field,
+			format!(
+				"field `{name}` is listed in {} but marked as {}",
+				ContainerAttributes::ONLY_IDENT,
+				DissolvedOption::SKIP_IDENT,
+			),
+		));
+	}
+
+	if !is_listed && info.renamed_to.is_some() {
+		return Err(Error::new_spanned(
+			field,
+			format!(
+				"cannot use {} on field `{name}`, which is not listed in {}",
+				DissolvedOption::RENAME_IDENT,
+				ContainerAttributes::ONLY_IDENT,
+			),
+		));
+	}
+
+	info.should_skip |= !is_listed;
+
+	Ok(())
+}
+
+fn get_field_info(field: &Field, container_attrs: &ContainerAttributes) -> Result<FieldInfo> {
+	// `dissolve` is a helper attribute of the derive too, so on a field it would be silently ignored
+	if let Some(attr) =
+		field.attrs.iter().find(|attr| attr.path().is_ident(ContainerAttributes::IDENT))
+	{
+		return Err(misplaced_container_attribute(attr));
+	}
+
+	let mut options = Vec::new();
 
 	for attr in field.attrs.iter().filter(|attr| attr.path().is_ident(DissolvedOption::IDENT)) {
-		match attr.meta.clone() {
+		match &attr.meta {
 			Meta::List(_) => {
 				// Parse #[dissolved(skip)] or #[dissolved(rename = "new_name")]
 				let nested_metas = attr.parse_args_with(
@@ -639,45 +5498,7 @@ fn get_field_info(field: &Field) -> Result<FieldInfo> {
 				)?;
 
 				for nested_meta in nested_metas {
-					let option = DissolvedOption::from_meta(&nested_meta)?;
-					match option {
-						DissolvedOption::Skip => {
-							if field_info.renamed_to.is_some() {
-								return Err(Error::new_spanned(
-									attr,
-									format!(
-										"cannot use {} on skipped field",
-										DissolvedOption::RENAME_IDENT,
-									),
-								));
-							}
-
-							field_info.should_skip = true;
-						},
-						DissolvedOption::Rename(new_ident) => {
-							if field_info.should_skip {
-								return Err(Error::new_spanned(
-									attr,
-									format!(
-										"cannot use {} on skipped field",
-										DissolvedOption::RENAME_IDENT,
-									),
-								));
-							}
-
-							if field_info.renamed_to.is_some() {
-								return Err(Error::new_spanned(
-									attr,
-									format!(
-										"cannot specify multiple {} options on the same field",
-										DissolvedOption::RENAME_IDENT,
-									),
-								));
-							}
-
-							field_info.renamed_to = Some(new_ident);
-						},
-					}
+					options.push((DissolvedOption::from_meta(&nested_meta)?, nested_meta, attr));
 				}
 			},
 			Meta::Path(_) => {
@@ -702,5 +5523,728 @@ fn get_field_info(field: &Field) -> Result<FieldInfo> {
 		}
 	}
 
-	Ok(field_info)
+	// Combinations are only validated once every option has been collected, so the reported
+	// error does not depend on the order in which the options were written.
+	let should_skip = options.iter().any(|(option, ..)| *option == DissolvedOption::Skip);
+
+	let keep_for_rebuild = options
+		.iter()
+		.find(|(option, ..)| *option == DissolvedOption::KeepForRebuild)
+		.map(|(_, meta, _)| meta.clone());
+
+	let keep = options
+		.iter()
+		.find(|(option, ..)| *option == DissolvedOption::Keep)
+		.map(|(_, meta, _)| meta.clone());
+
+	let mut defaults = options.iter().filter_map(|(option, meta, attr)| match option {
+		DissolvedOption::Default(value) => Some((meta, value, attr)),
+		_ => None,
+	});
+	let default = defaults.next();
+
+	if let Some((.., attr)) = defaults.next() {
+		return Err(Error::new_spanned(
+			attr,
+			format!(
+				"cannot specify multiple {} options on the same field",
+				DissolvedOption::DEFAULT_IDENT,
+			),
+		));
+	}
+
+	let (default, default_value) = match default {
+		Some((meta, value, _)) => (Some(meta.clone()), value.as_deref().cloned()),
+		None => (None, None),
+	};
+
+	for (meta, option_name) in [
+		(&keep_for_rebuild, DissolvedOption::KEEP_FOR_REBUILD_IDENT),
+		(&keep, DissolvedOption::KEEP_IDENT),
+		(&default, DissolvedOption::DEFAULT_IDENT),
+	] {
+		if let Some(meta) = meta.as_ref().filter(|_| !should_skip) {
+			return Err(Error::new_spanned(
+				meta,
+				format!(
+					"{option_name} can only be used on skipped fields, add {} to the same field",
+					DissolvedOption::SKIP_IDENT,
+				),
+			));
+		}
+	}
+
+	if let Some(meta) = keep.as_ref().filter(|_| keep_for_rebuild.is_some()) {
+		return Err(Error::new_spanned(
+			meta,
+			format!(
+				"{} cannot be combined with {}, a kept field is already passed back to into_original",
+				DissolvedOption::KEEP_IDENT,
+				DissolvedOption::KEEP_FOR_REBUILD_IDENT,
+			),
+		));
+	}
+
+	for (kept, option_name) in [
+		(&keep_for_rebuild, DissolvedOption::KEEP_FOR_REBUILD_IDENT),
+		(&keep, DissolvedOption::KEEP_IDENT),
+	] {
+		if let Some(meta) = default.as_ref().filter(|_| kept.is_some()) {
+			return Err(Error::new_spanned(
+				meta,
+				format!(
+					"{} cannot be combined with {option_name}, which hands the field back in the carrier",
+					DissolvedOption::DEFAULT_IDENT,
+				),
+			));
+		}
+	}
+
+	let mut renames = options.iter().filter_map(|(option, _, attr)| match option {
+		DissolvedOption::Rename(new_ident) => Some((new_ident, attr)),
+		_ => None,
+	});
+	let rename = renames.next();
+
+	if let Some((_, attr)) = renames.next() {
+		return Err(Error::new_spanned(
+			attr,
+			format!(
+				"cannot specify multiple {} options on the same field",
+				DissolvedOption::RENAME_IDENT,
+			),
+		));
+	}
+
+	let mut rename_withs = options.iter().filter_map(|(option, _, attr)| match option {
+		DissolvedOption::RenameWith(transforms, lit_str) => Some((transforms, lit_str, attr)),
+		_ => None,
+	});
+	let rename_with = rename_withs.next();
+
+	if let Some((.., attr)) = rename_withs.next() {
+		return Err(Error::new_spanned(
+			attr,
+			format!(
+				"cannot specify multiple {} options on the same field",
+				DissolvedOption::RENAME_WITH_IDENT,
+			),
+		));
+	}
+
+	// An explicit rename takes precedence, so the transforms are not even applied
+	let renamed_to = match (rename, rename_with) {
+		(Some((new_ident, attr)), _) => Some((new_ident.clone(), attr, false)),
+		(None, Some((transforms, lit_str, attr))) => {
+			let new_ident = apply_rename_transforms(
+				field,
+				transforms,
+				lit_str,
+				DissolvedOption::RENAME_WITH_IDENT,
+			)?;
+
+			Some((new_ident, attr, true))
+		},
+		(None, None) => None,
+	};
+
+	// With `recompose`, a skipped field becomes a parameter of `into_original`, which the rename
+	// applies to, unless the field is kept for rebuild or defaulted and never becomes a parameter
+	let rename_is_used = container_attrs.recompose
+		&& keep_for_rebuild.is_none()
+		&& keep.is_none()
+		&& default.is_none();
+
+	if let Some((_, attr, _)) = renamed_to.as_ref().filter(|_| should_skip && !rename_is_used) {
+		return Err(Error::new_spanned(
+			attr,
+			format!(
+				"cannot use {} on skipped field",
+				DissolvedOption::RENAME_IDENT
+			),
+		));
+	}
+
+	let mut groups = options.iter().filter_map(|(option, _, attr)| match option {
+		DissolvedOption::Group(group) => Some((group, attr)),
+		_ => None,
+	});
+
+	let group = groups.next();
+
+	if let Some((_, attr)) = groups.next() {
+		return Err(Error::new_spanned(
+			attr,
+			format!(
+				"cannot specify multiple {} options on the same field",
+				DissolvedOption::GROUP_IDENT,
+			),
+		));
+	}
+
+	if let Some((group, _)) = group.filter(|_| should_skip) {
+		return Err(Error::new_spanned(
+			group,
+			format!(
+				"cannot use {} on skipped field",
+				DissolvedOption::GROUP_IDENT
+			),
+		));
+	}
+
+	let mut aliases = options.iter().filter_map(|(option, _, attr)| match option {
+		DissolvedOption::Alias(alias) => Some((alias, attr)),
+		_ => None,
+	});
+
+	let alias = aliases.next();
+
+	if let Some((_, attr)) = aliases.next() {
+		return Err(Error::new_spanned(
+			attr,
+			format!(
+				"cannot specify multiple {} options on the same field",
+				DissolvedOption::ALIAS_IDENT,
+			),
+		));
+	}
+
+	if let Some((alias, _)) = alias.filter(|_| should_skip) {
+		return Err(Error::new_spanned(
+			alias,
+			format!(
+				"cannot use {} on skipped field",
+				DissolvedOption::ALIAS_IDENT
+			),
+		));
+	}
+
+	let mut withs = options.iter().filter_map(|(option, meta, _)| match option {
+		DissolvedOption::With(with) => Some((with, meta)),
+		_ => None,
+	});
+	let with = withs.next();
+
+	let mut tys = options.iter().filter_map(|(option, meta, _)| match option {
+		DissolvedOption::Ty(ty) => Some((&**ty, meta)),
+		_ => None,
+	});
+	let ty = tys.next();
+
+	for (option_name, meta) in [
+		(
+			DissolvedOption::WITH_IDENT,
+			withs.next().map(|(_, meta)| meta),
+		),
+		(DissolvedOption::TY_IDENT, tys.next().map(|(_, meta)| meta)),
+	] {
+		if let Some(meta) = meta {
+			return Err(Error::new_spanned(
+				meta,
+				format!("cannot specify multiple {option_name} options on the same field"),
+			));
+		}
+	}
+
+	let with_or_ty = with.map(|(_, meta)| meta).or(ty.map(|(_, meta)| meta));
+
+	if let Some(meta) = with_or_ty.filter(|_| should_skip) {
+		return Err(Error::new_spanned(
+			meta,
+			format!(
+				"cannot use {} or {} on skipped field",
+				DissolvedOption::WITH_IDENT,
+				DissolvedOption::TY_IDENT,
+			),
+		));
+	}
+
+	let cow = options
+		.iter()
+		.find(|(option, ..)| *option == DissolvedOption::Cow)
+		.map(|(_, meta, _)| meta);
+
+	if let Some(meta) = cow.filter(|_| with_or_ty.is_some()) {
+		return Err(Error::new_spanned(
+			meta,
+			format!(
+				"{} cannot be combined with {} or {}, as it already converts the field",
+				DissolvedOption::COW_IDENT,
+				DissolvedOption::WITH_IDENT,
+				DissolvedOption::TY_IDENT,
+			),
+		));
+	}
+
+	if let Some(meta) = cow.filter(|_| should_skip) {
+		return Err(Error::new_spanned(
+			meta,
+			format!("cannot use {} on skipped field", DissolvedOption::COW_IDENT),
+		));
+	}
+
+	let with = match (with, ty) {
+		(Some((with, _)), Some((ty, _))) => Some((with.clone(), ty.clone())),
+		(Some((_, meta)), None) => {
+			return Err(Error::new_spanned(
+				meta,
+				format!(
+					"{} requires {} on the same field, giving the type the function returns",
+					DissolvedOption::WITH_IDENT,
+					DissolvedOption::TY_IDENT,
+				),
+			));
+		},
+		(None, Some((_, meta))) => {
+			return Err(Error::new_spanned(
+				meta,
+				format!(
+					"{} requires {} on the same field, giving the function converting the field to this type",
+					DissolvedOption::TY_IDENT,
+					DissolvedOption::WITH_IDENT,
+				),
+			));
+		},
+		(None, None) => cow.map(|_| {
+			(
+				syn::parse_quote!(::std::borrow::Cow::Owned),
+				cow_type(&field.ty),
+			)
+		}),
+	};
+
+	// These read the fields of `self` as the type they are dissolved to
+	let original_type_options = [
+		(
+			container_attrs.recompose,
+			ContainerAttributes::RECOMPOSE_IDENT,
+		),
+		(
+			container_attrs.from_dissolved,
+			ContainerAttributes::FROM_DISSOLVED_IDENT,
+		),
+		(
+			container_attrs.snapshot.is_some(),
+			ContainerAttributes::SNAPSHOT_IDENT,
+		),
+		(
+			container_attrs.copy_view,
+			ContainerAttributes::COPY_VIEW_IDENT,
+		),
+		(container_attrs.drain, ContainerAttributes::DRAIN_IDENT),
+		(container_attrs.as_ref, ContainerAttributes::AS_REF_IDENT),
+	];
+
+	let conversion = match cow {
+		Some(meta) => Some((meta.to_token_stream(), DissolvedOption::COW_IDENT)),
+		None => {
+			with.as_ref().map(|(with, _)| (with.to_token_stream(), DissolvedOption::WITH_IDENT))
+		},
+	};
+
+	if let Some(((_, option), (tokens, option_name))) =
+		original_type_options.iter().find(|(enabled, _)| *enabled).zip(conversion)
+	{
+		return Err(Error::new_spanned(
+			tokens,
+			format!(
+				"{option_name} cannot be combined with {option}, which needs the dissolved value to have the type of the field",
+			),
+		));
+	}
+
+	let (with, ty) = with.unzip();
+
+	// The container's `rename_all` names the included fields that are not renamed themselves;
+	// fields of tuple structs and tuple variants have no name to transform
+	let (renamed_to, renamed_by_transform) = match (renamed_to, &container_attrs.rename_all) {
+		(Some((new_ident, _, by_transform)), _) => (Some(new_ident), by_transform),
+		(None, Some((transforms, lit_str)))
+			if field.ident.is_some()
+				&& !should_skip
+				&& selected_by_container(field, container_attrs) =>
+		{
+			let new_ident = apply_rename_transforms(
+				field,
+				transforms,
+				lit_str,
+				ContainerAttributes::RENAME_ALL_IDENT,
+			)?;
+
+			(Some(new_ident), true)
+		},
+		(None, _) => (None, false),
+	};
+
+	Ok(FieldInfo {
+		should_skip,
+		keep_for_rebuild,
+		keep,
+		default,
+		default_value,
+		renamed_to,
+		renamed_by_transform,
+		group: group.map(|(group, _)| group.clone()),
+		alias: alias.map(|(alias, _)| alias.clone()),
+		with,
+		ty,
+	})
+}
+
+fn generate_enum_impl(
+	enum_name: &syn::Ident,
+	generics: &syn::Generics,
+	data_enum: &syn::DataEnum,
+	container_attrs: &ContainerAttributes,
+) -> Result<proc_macro2::TokenStream> {
+	let struct_only_options = [
+		(
+			container_attrs.recompose,
+			ContainerAttributes::RECOMPOSE_IDENT,
+		),
+		(
+			container_attrs.into_tuple,
+			ContainerAttributes::INTO_TUPLE_IDENT,
+		),
+		(
+			container_attrs.from_dissolved,
+			ContainerAttributes::FROM_DISSOLVED_IDENT,
+		),
+		(
+			container_attrs.transition_to.is_some(),
+			ContainerAttributes::TRANSITION_TO_IDENT,
+		),
+		(container_attrs.seal, ContainerAttributes::SEAL_IDENT),
+		(
+			container_attrs.doc_provenance,
+			ContainerAttributes::DOC_PROVENANCE_IDENT,
+		),
+		(
+			container_attrs.prune_generics,
+			ContainerAttributes::PRUNE_GENERICS_IDENT,
+		),
+		(
+			container_attrs.inherit_field_vis,
+			ContainerAttributes::INHERIT_FIELD_VIS_IDENT,
+		),
+		(
+			container_attrs.test_helpers,
+			ContainerAttributes::TEST_HELPERS_IDENT,
+		),
+		(container_attrs.newtype, ContainerAttributes::NEWTYPE_IDENT),
+		(container_attrs.array, ContainerAttributes::ARRAY_IDENT),
+		(
+			container_attrs.manually_drop,
+			ContainerAttributes::MANUALLY_DROP_IDENT,
+		),
+		(
+			container_attrs.move_strategy_span.is_some(),
+			ContainerAttributes::MOVE_STRATEGY_IDENT,
+		),
+		(
+			container_attrs.only.is_some(),
+			ContainerAttributes::ONLY_IDENT,
+		),
+		(
+			container_attrs.only_public.is_some(),
+			ContainerAttributes::ONLY_PUBLIC_IDENT,
+		),
+		(
+			container_attrs.snapshot.is_some(),
+			ContainerAttributes::SNAPSHOT_IDENT,
+		),
+		(
+			container_attrs.copy_view,
+			ContainerAttributes::COPY_VIEW_IDENT,
+		),
+		(container_attrs.drain, ContainerAttributes::DRAIN_IDENT),
+		(container_attrs.boxed, ContainerAttributes::BOXED_IDENT),
+		(
+			container_attrs.transparent,
+			ContainerAttributes::TRANSPARENT_IDENT,
+		),
+		(container_attrs.as_ref, ContainerAttributes::AS_REF_IDENT),
+		(
+			container_attrs.field_extractors,
+			ContainerAttributes::FIELD_EXTRACTORS_IDENT,
+		),
+		(container_attrs.nested, ContainerAttributes::NESTED_IDENT),
+		(container_attrs.opaque, ContainerAttributes::OPAQUE_IDENT),
+		(container_attrs.dynamic, ContainerAttributes::DYNAMIC_IDENT),
+		(container_attrs.tagged, ContainerAttributes::TAGGED_IDENT),
+		(
+			container_attrs.tuple_from,
+			ContainerAttributes::TUPLE_FROM_IDENT,
+		),
+	];
+
+	if let Some((_, option)) = struct_only_options.iter().find(|(enabled, _)| *enabled) {
+		return Err(Error::new_spanned(
+			enum_name,
+			format!("{option} is not supported for enums"),
+		));
+	}
+
+	let dissolved_enum_name = format_ident!("{}Dissolved", enum_name);
+
+	let mut variant_definitions = Vec::new();
+	let mut match_arms = Vec::new();
+	let mut allow_non_snake_case = None;
+	let mut included_types = Vec::new();
+
+	for variant in &data_enum.variants {
+		let variant_name = &variant.ident;
+		let kept_attrs = variant
+			.attrs
+			.iter()
+			.filter(|attr| container_attrs.keeps_attr(attr))
+			.map(|attr| container_attrs.forward_attr(attr));
+
+		match &variant.fields {
+			Fields::Named(fields) => {
+				let all_fields = fields
+					.named
+					.iter()
+					.map(|field| {
+						let info = get_field_info(field, container_attrs)?;
+						info.reject_named_only_options("struct variant")?;
+
+						Ok((field, info))
+					})
+					.collect::<Result<Vec<_>>>()?;
+
+				let included_fields: Vec<_> =
+					all_fields.iter().filter(|(_, info)| !info.should_skip).collect();
+				included_types.extend(included_fields.iter().map(|(field, _)| field.ty.clone()));
+
+				// Each variant has its own fields, so names only collide within a variant
+				reject_dissolved_name_collisions(
+					&included_fields,
+					&format!("the dissolved variant `{variant_name}`"),
+				)?;
+
+				let field_definitions = included_fields.iter().map(|(field, info)| {
+					// unwrap is safe because variant has named fields
+					let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+					let field_attrs = field
+						.attrs
+						.iter()
+						.filter(|attr| container_attrs.keeps_attr(attr))
+						.map(|attr| container_attrs.forward_attr(attr));
+					let ty = &field.ty;
+
+					quote! {
+						#(#field_attrs)*
+						#dissolved_field_name: #ty
+					}
+				});
+
+				// Included fields are bound to their dissolved name, so they can be used as-is
+				let field_patterns = included_fields.iter().map(|(field, info)| {
+					// unwrap is safe because variant has named fields
+					let original_name = field.ident.as_ref().unwrap();
+					let dissolved_field_name = info.dissolved_name(original_name);
+
+					if original_name == dissolved_field_name {
+						quote! { #original_name }
+					} else {
+						quote! { #original_name: #dissolved_field_name }
+					}
+				});
+
+				let field_inits = included_fields.iter().map(|(field, info)| {
+					// unwrap is safe because variant has named fields
+					let dissolved_field_name = info.dissolved_name(field.ident.as_ref().unwrap());
+
+					match &info.with {
+						Some(with) => {
+							quote! { #dissolved_field_name: #with(#dissolved_field_name) }
+						},
+						None => quote! { #dissolved_field_name },
+					}
+				});
+
+				variant_definitions.push(quote! {
+					#(#kept_attrs)*
+					#variant_name {
+						#(#field_definitions),*
+					}
+				});
+
+				// The fields of the dissolved enum and the bindings of `dissolve` share the names
+				allow_non_snake_case = allow_non_snake_case.or_else(|| {
+					allow_transformed_names(included_fields.iter().map(|(_, info)| info))
+				});
+
+				match_arms.push(quote! {
+					Self::#variant_name { #(#field_patterns,)* .. } => {
+						#dissolved_enum_name::#variant_name { #(#field_inits),* }
+					}
+				});
+			},
+			Fields::Unnamed(fields) => {
+				let all_fields = fields
+					.unnamed
+					.iter()
+					.map(|field| {
+						let info = get_field_info(field, container_attrs)?;
+						info.reject_named_only_options("tuple variant")?;
+
+						// Check if rename was attempted on tuple variant
+						if info.renamed_to.is_some() {
+							return Err(Error::new_spanned(
+								field,
+								format!(
+									"{} is unsupported for tuple variant fields, only {} is allowed",
+									DissolvedOption::RENAME_IDENT,
+									DissolvedOption::SKIP_IDENT,
+								),
+							));
+						}
+
+						Ok((field, info))
+					})
+					.collect::<Result<Vec<_>>>()?;
+
+				included_types.extend(
+					all_fields
+						.iter()
+						.filter(|(_, info)| !info.should_skip)
+						.map(|(field, _)| field.ty.clone()),
+				);
+
+				let field_definitions =
+					all_fields.iter().filter(|(_, info)| !info.should_skip).map(|(field, _)| {
+						let field_attrs = field
+							.attrs
+							.iter()
+							.filter(|attr| container_attrs.keeps_attr(attr))
+							.map(|attr| container_attrs.forward_attr(attr));
+						let ty = &field.ty;
+
+						quote! {
+							#(#field_attrs)*
+							#ty
+						}
+					});
+
+				let field_inits: Vec<_> = all_fields
+					.iter()
+					.enumerate()
+					.filter(|(_, (_, info))| !info.should_skip)
+					.map(|(index, (_, info))| {
+						let binding = format_ident!("field_{}", index);
+
+						match &info.with {
+							Some(with) => quote! { #with(#binding) },
+							None => quote! { #binding },
+						}
+					})
+					.collect();
+
+				let field_patterns = all_fields.iter().enumerate().map(|(index, (_, info))| {
+					if info.should_skip {
+						quote! { _ }
+					} else {
+						let binding = format_ident!("field_{}", index);
+						quote! { #binding }
+					}
+				});
+
+				variant_definitions.push(quote! {
+					#(#kept_attrs)*
+					#variant_name(#(#field_definitions),*)
+				});
+
+				match_arms.push(quote! {
+					Self::#variant_name(#(#field_patterns),*) => {
+						#dissolved_enum_name::#variant_name(#(#field_inits),*)
+					}
+				});
+			},
+			Fields::Unit => {
+				variant_definitions.push(quote! {
+					#(#kept_attrs)*
+					#variant_name
+				});
+
+				match_arms.push(quote! {
+					Self::#variant_name => #dissolved_enum_name::#variant_name
+				});
+			},
+		}
+	}
+
+	let derives = container_attrs.derives_attr();
+
+	let method_link = container_attrs.method_doc_link(enum_name);
+	let dissolved_enum_doc = format!(
+		"Dissolved enum for [`{enum_name}`].\n\n\
+		This enum has the same variants as the original enum, without their skipped fields. \
+		The visibility of this enum matches the visibility of the {method_link} method. \
+		Fields of struct variants may be renamed according to `#[dissolved(rename = \"...\")]` \
+		attributes.",
+	);
+
+	// An enum has no room for a marker field, so type and lifetime parameters only used by skipped
+	// fields are left off the dissolved enum, together with any unused const parameter. When every
+	// parameter is still used, the dissolved enum keeps the generics of the original.
+	let used = referenced_names_in_types(&included_types);
+	let has_unused_param = generics.params.iter().any(|param| {
+		!matches!(param, syn::GenericParam::Const(_)) && !used.contains(&generic_param_name(param))
+	});
+	let dissolved_generics = if has_unused_param {
+		prune_generics(generics, &included_types).kept
+	} else {
+		generics.clone()
+	};
+
+	// Split generics for use in different positions
+	let (impl_generics, ty_generics, where_clause) = dissolved_generics.split_for_impl();
+	let visibility = &container_attrs.visibility;
+	let map_dissolved = map_dissolved_method(
+		&quote! { #dissolved_enum_name #ty_generics },
+		generics,
+		container_attrs,
+	);
+
+	let to_json = to_json_method(false, container_attrs);
+
+	let dissolve_doc = format!(
+		" Dissolve this enum into its dissolved equivalent.\n\n \
+		This method consumes the original enum and returns the matching variant of {}, holding \
+		the included fields of that variant.",
+		container_attrs.dissolved_doc_link(&dissolved_enum_name),
+	);
+
+	let dissolve_doc = container_attrs.auto_doc(quote! { #[doc = #dissolve_doc] });
+	let dissolve_item = dissolve_item(
+		enum_name,
+		generics,
+		quote! {
+			#dissolve_doc
+			#allow_non_snake_case
+		},
+		&quote! { #dissolved_enum_name #ty_generics },
+		quote! {
+			match self {
+				#(#match_arms),*
+			}
+		},
+		quote! { #map_dissolved #to_json },
+		container_attrs,
+	);
+
+	let dissolved_enum_doc = container_attrs.auto_doc(quote! { #[doc = #dissolved_enum_doc] });
+
+	Ok(quote! {
+		#dissolved_enum_doc
+		#derives
+		#allow_non_snake_case
+		#visibility enum #dissolved_enum_name #impl_generics #where_clause {
+			#(#variant_definitions),*
+		}
+
+		#dissolve_item
+	})
 }
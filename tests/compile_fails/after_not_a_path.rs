@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(after = "on dissolve")]
+struct Job {
+	name: String,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Server {
+	#[dissolved(rename = "host", alias = "hostname")]
+	hostname: String,
+}
+
+fn main() {
+	let server = Server { hostname: "example.org".to_string() };
+	let _ = server.dissolve().hostname();
+}
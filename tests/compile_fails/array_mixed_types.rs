@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(array)]
+struct Mixed(u8, u16, u8);
+
+fn main() {}
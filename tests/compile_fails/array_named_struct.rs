@@ -0,0 +1,11 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(array)]
+struct Rgb {
+	r: u8,
+	g: u8,
+	b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(assert_size)]
+struct Reading {
+	#[dissolved(with = "u64::from", ty = "u64")]
+	value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[dissolve_derive::dissolve(mapp)]
+struct Config {
+	#[dissolved(skip)]
+	name: String,
+	retries: u8,
+}
+
+fn main() {
+	// The item is still emitted, so only the unknown option is reported
+	let _config = Config { name: String::new(), retries: 3 };
+}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(boxed_self, map)]
+struct Builder {
+	items: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(cfg = "feature = extract")]
+struct Sample {
+	value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(copy_view)]
+struct Config {
+	name: String,
+	retries: u8,
+}
+
+fn main() {}
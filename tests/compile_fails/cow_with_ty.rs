@@ -0,0 +1,16 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Document {
+	#[dissolved(cow, with = "String::into_boxed_str", ty = "Box<str>")]
+	title: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(snapshot)]
+struct Note {
+	#[dissolved(cow)]
+	body: String,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct WithoutRecompose {
+	id: u32,
+
+	#[dissolved(skip, default)]
+	validated: bool,
+}
+
+#[derive(Dissolve)]
+#[dissolve(recompose)]
+struct WithoutSkip {
+	#[dissolved(default)]
+	id: u32,
+}
+
+#[derive(Dissolve)]
+#[dissolve(recompose)]
+struct WithKeep {
+	id: u32,
+
+	#[dissolved(skip, keep, default)]
+	validated: bool,
+}
+
+#[derive(Dissolve)]
+#[dissolve(recompose)]
+struct NotAnExpression {
+	id: u32,
+
+	#[dissolved(skip, default = "1 +")]
+	validated: bool,
+}
+
+fn main() {}
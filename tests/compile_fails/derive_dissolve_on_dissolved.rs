@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(derive(Debug, Dissolve))]
+struct Reading {
+	celsius: i16,
+}
+
+fn main() {}
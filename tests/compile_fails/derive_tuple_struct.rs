@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(derive(Debug))]
+struct Pair(String, u8);
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dissolve_derive::dissolve_for;
+
+dissolve_for! {
+	#[dissolve(map)]
+	struct Range<Idx> {
+		start: Idx,
+		end: Idx,
+	}
+}
+
+fn main() {}
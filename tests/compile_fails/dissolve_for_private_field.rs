@@ -0,0 +1,19 @@
+use dissolve_derive::dissolve_for;
+
+mod remote {
+	pub struct Point {
+		pub x: i32,
+		y: i32,
+	}
+}
+
+use remote::Point;
+
+dissolve_for! {
+	struct Point {
+		x: i32,
+		y: i32,
+	}
+}
+
+fn main() {}
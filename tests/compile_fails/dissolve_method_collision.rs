@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Solution {
+	solvent: String,
+}
+
+impl Solution {
+	fn dissolve(&mut self) {}
+}
+
+fn main() {}
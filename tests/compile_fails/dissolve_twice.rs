@@ -0,0 +1,13 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct DissolveTwice {
+	field: String,
+}
+
+fn main() {
+	let s = DissolveTwice { field: "value".into() };
+
+	let _first = s.dissolve();
+	let _second = s.dissolve();
+}
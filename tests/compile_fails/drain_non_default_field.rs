@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+struct Handle(u32);
+
+#[derive(Dissolve)]
+#[dissolve(drain)]
+struct Connection {
+	name: String,
+	handle: Handle,
+}
+
+fn main() {}
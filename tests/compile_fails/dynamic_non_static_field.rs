@@ -0,0 +1,13 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(dynamic)]
+struct Borrowed<'a> {
+	name: &'a str,
+}
+
+fn main() {
+	let name = String::from("borrowed");
+	let borrowed = Borrowed { name: &name };
+	let _ = borrowed.dissolve_dynamic();
+}
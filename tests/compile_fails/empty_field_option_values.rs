@@ -0,0 +1,28 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(nested)]
+struct Grouped {
+	#[dissolved(group = "")]
+	field: String,
+}
+
+#[derive(Dissolve)]
+struct Aliased {
+	#[dissolved(alias = " ")]
+	field: String,
+}
+
+#[derive(Dissolve)]
+struct Converted {
+	#[dissolved(with = "", ty = "")]
+	field: String,
+}
+
+#[derive(Dissolve)]
+struct Transformed {
+	#[dissolved(rename_with = "")]
+	field: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(recompose)]
+enum State {
+	Idle,
+	Running { pid: u32 },
+}
+
+fn main() {}
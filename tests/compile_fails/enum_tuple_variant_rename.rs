@@ -0,0 +1,8 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+enum State {
+	Failed(#[dissolved(rename = "reason")] String),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Config {
+	#[dissolve(snapshot)]
+	retries: u8,
+}
+
+fn main() {}
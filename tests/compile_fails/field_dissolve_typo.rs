@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Config {
+	#[dissolve(skip)]
+	secret: String,
+
+	#[dissolve(rename = "retry_count")]
+	retries: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(recompose, field_extractors)]
+struct Draft {
+	original: String,
+	edited: String,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+mod inner {
+	use dissolve_derive::Dissolve;
+
+	#[derive(Dissolve)]
+	#[dissolve(fields_visibility = "mirror")]
+	pub struct Account {
+		pub id: u64,
+		secret: String,
+	}
+
+	pub fn account() -> Account {
+		Account { id: 1, secret: "hunter2".into() }
+	}
+}
+
+fn main() {
+	let dissolved = inner::account().dissolve();
+	let _ = dissolved.id;
+	let _ = dissolved.secret;
+}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(fields_visibility = "crate")]
+struct Account {
+	id: u64,
+}
+
+fn main() {}
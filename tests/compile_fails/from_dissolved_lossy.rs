@@ -0,0 +1,19 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(from_dissolved)]
+struct Session {
+	id: u32,
+
+	#[dissolved(skip)]
+	token: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(from_dissolved)]
+struct Reading {
+	#[dissolved(with = "u32::to_string", ty = "String")]
+	id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(nested)]
+struct Customer {
+	address: String,
+
+	#[dissolved(group = "address")]
+	street: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Pair(#[dissolved(group = "first")] String, u8);
+
+fn main() {}
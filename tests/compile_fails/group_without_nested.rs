@@ -0,0 +1,11 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Customer {
+	name: String,
+
+	#[dissolved(group = "address")]
+	street: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+mod inner {
+	use dissolve_derive::Dissolve;
+
+	#[derive(Dissolve)]
+	#[dissolve(inherit_field_vis)]
+	pub struct Account {
+		pub id: u64,
+		secret: String,
+	}
+}
+
+fn read_secret(dissolved: inner::AccountDissolved) -> String {
+	dissolved.secret
+}
+
+fn main() {}
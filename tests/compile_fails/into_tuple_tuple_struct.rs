@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(into_tuple)]
+struct Pair(u32, String);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(keep_attrs(doc, dissolved))]
+struct Config {
+	#[dissolved(rename = "addr")]
+	address: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(keep_attrs(deprecated))]
+struct Config {
+	#[deprecated = "use `endpoint` instead"]
+	address: String,
+	endpoint: String,
+}
+
+fn read_address(dissolved: ConfigDissolved) -> String {
+	dissolved.address
+}
+
+fn main() {}
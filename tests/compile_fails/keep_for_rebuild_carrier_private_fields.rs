@@ -0,0 +1,22 @@
+mod session {
+	use dissolve_derive::Dissolve;
+
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	pub struct Session {
+		pub user_id: u64,
+		#[dissolved(skip, keep_for_rebuild)]
+		token: String,
+	}
+
+	impl Session {
+		pub fn new() -> Self {
+			Self { user_id: 1, token: "secret".into() }
+		}
+	}
+}
+
+fn main() {
+	let (_, carrier) = session::Session::new().dissolve();
+	let _token = carrier.token;
+}
@@ -0,0 +1,11 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(recompose)]
+struct Session {
+	user_id: u64,
+	#[dissolved(skip, keep_for_rebuild, rename = "secret")]
+	token: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Session(u64, #[dissolved(skip, keep_for_rebuild)] String);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Session {
+	user_id: u64,
+	#[dissolved(skip, keep_for_rebuild)]
+	token: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Request {
+	path: String,
+	#[dissolved(keep)]
+	headers: Vec<String>,
+}
+
+fn main() {}
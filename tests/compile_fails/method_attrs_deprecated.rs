@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(method_attrs(deprecated = "use into_parts instead"))]
+struct Connection {
+	socket: String,
+}
+
+fn main() {
+	let connection = Connection { socket: "localhost".into() };
+	let _ = connection.dissolve();
+}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(move_strategy = "destructure", manually_drop)]
+struct MoveStrategyWithManuallyDrop {
+	field: String,
+}
+
+fn main() {}
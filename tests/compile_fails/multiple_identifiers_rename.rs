@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct MultipleIdentifiersRename {
+	#[dissolved(rename = "first second")]
+	field: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(newtype)]
+struct Point(f64, f64);
+
+fn main() {}
@@ -0,0 +1,16 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(newtype, recompose)]
+struct Meters {
+	value: f64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(newtype)]
+struct Feet {
+	#[dissolved(rename = "length")]
+	value: f64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(newtype)]
+struct Meters(#[dissolved(skip)] f64);
+
+fn main() {}
@@ -0,0 +1,15 @@
+//! The generated docs are left out, so `missing_docs` reports the dissolved struct, while the
+//! method is documented through `method_attrs`.
+#![deny(missing_docs)]
+
+use dissolve_derive::Dissolve;
+
+/// A user.
+#[derive(Dissolve)]
+#[dissolve(no_auto_doc, method_attrs(doc = "Split the user into its fields."))]
+pub struct User {
+	/// The name of the user.
+	pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(only_public, only("name"))]
+pub struct Account {
+	pub name: String,
+	password_hash: String,
+}
+
+#[derive(Dissolve)]
+#[dissolve(only_public)]
+pub struct Profile {
+	pub name: String,
+
+	#[dissolved(rename = "secret")]
+	password_hash: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(only("socket", "buffr"))]
+struct Connection {
+	socket: String,
+	buffer: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(only("socket", "buffer"))]
+struct Connection {
+	socket: String,
+
+	#[dissolved(skip)]
+	buffer: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(opaque)]
+struct Pair(String, u8);
+
+fn main() {}
@@ -0,0 +1,14 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(output = "struct")]
+struct Point {
+	x: i32,
+	y: i32,
+}
+
+#[derive(Dissolve)]
+#[dissolve(output = "record")]
+struct Pair(i32, i32);
+
+fn main() {}
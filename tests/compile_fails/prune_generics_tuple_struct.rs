@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(prune_generics)]
+struct Pair<T, U>(T, #[dissolved(skip)] U);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(recompose)]
+struct TupleRecompose(String, i32);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(recompose = "yes")]
+struct RecomposeWithValue {
+	field: String,
+}
+
+fn main() {}
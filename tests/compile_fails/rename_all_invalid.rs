@@ -0,0 +1,16 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(rename_all = "title")]
+struct Sample {
+	value: u16,
+}
+
+#[derive(Dissolve)]
+#[dissolve(rename_all = "strip_prefix:raw_")]
+struct Reading {
+	raw_value: u16,
+	taken: u64,
+}
+
+fn main() {}
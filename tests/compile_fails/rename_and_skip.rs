@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct RenameAndSkip {
+	#[dissolved(rename = "new_name", skip)]
+	field: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Pair {
+	a: u32,
+
+	#[dissolved(rename = "a")]
+	b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Pair {
+	#[dissolved(rename_with = "strip_prefix:raw_")]
+	raw_b: u32,
+
+	b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+enum Reading {
+	Temperature {
+		value: f64,
+
+		#[dissolved(rename = "value")]
+		celsius: f64,
+	},
+}
+
+fn main() {}
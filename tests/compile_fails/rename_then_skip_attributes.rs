@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct RenameThenSkip {
+	#[dissolved(rename = "new_name")]
+	#[dissolved(skip)]
+	field: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct RenameToSelf {
+	#[dissolved(rename = "self")]
+	field: String,
+}
+
+fn main() {}
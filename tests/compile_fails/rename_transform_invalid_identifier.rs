@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Buffers {
+	#[dissolved(rename_with = "prefix:1")]
+	header: Vec<u8>,
+}
+
+fn main() {}
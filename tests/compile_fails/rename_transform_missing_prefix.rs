@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Buffers {
+	#[dissolved(rename_with = "strip_prefix:raw_")]
+	header: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+mod session {
+	use dissolve_derive::Dissolve;
+
+	#[derive(Dissolve)]
+	#[dissolve(seal)]
+	pub struct Session {
+		pub id: u32,
+	}
+}
+
+fn main() {
+	let _ = session::SessionDissolved { id: 1 };
+}
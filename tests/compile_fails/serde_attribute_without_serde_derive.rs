@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Config {
+	#[serde(rename = "host_name")]
+	host: String,
+}
+
+fn main() {}
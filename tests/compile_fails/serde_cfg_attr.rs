@@ -0,0 +1,14 @@
+// The generated serde derives and attributes check the `serde` feature of this crate, which does
+// not declare one, so the denied `unexpected_cfgs` lint shows that they are behind a `cfg_attr`
+#![deny(unexpected_cfgs)]
+
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve, serde::Serialize)]
+#[dissolve(serde)]
+struct Config {
+	#[serde(rename = "host_name")]
+	host: String,
+}
+
+fn main() {}
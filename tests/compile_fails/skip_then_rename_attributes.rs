@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct SkipThenRename {
+	#[dissolved(skip)]
+	#[dissolved(rename = "new_name")]
+	field: String,
+}
+
+fn main() {}
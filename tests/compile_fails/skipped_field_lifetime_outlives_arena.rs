@@ -0,0 +1,24 @@
+use dissolve_derive::Dissolve;
+
+struct Arena {
+	bytes: Vec<u8>,
+}
+
+#[derive(Dissolve)]
+struct Holder<'a> {
+	data: &'a [u8],
+	#[dissolved(skip)]
+	arena: &'a Arena,
+}
+
+fn main() {
+	let dissolved = {
+		let arena = Arena { bytes: vec![1, 2, 3] };
+		let holder = Holder { data: &arena.bytes, arena: &arena };
+
+		// The dissolved struct still borrows from the arena through `'a`
+		holder.dissolve()
+	};
+
+	let _ = dissolved.data;
+}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(snapshot)]
+enum Event {
+	Started(u64),
+	Stopped,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use dissolve_derive::Dissolve;
+
+struct NotClone;
+
+#[derive(Dissolve)]
+#[dissolve(snapshot)]
+struct Wrapper<T> {
+	value: T,
+}
+
+fn main() {
+	let wrapper = Wrapper { value: NotClone };
+	let _ = wrapper.snapshot();
+}
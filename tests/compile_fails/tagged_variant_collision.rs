@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(tagged)]
+struct Sample {
+	raw_value: u32,
+
+	#[dissolved(rename = "raw_value_")]
+	raw: u32,
+}
+
+fn main() {}
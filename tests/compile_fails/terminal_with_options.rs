@@ -0,0 +1,16 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(terminal, recompose)]
+struct Reading {
+	celsius: i16,
+}
+
+#[derive(Dissolve)]
+#[dissolve(terminal)]
+struct Sample {
+	#[dissolved(skip)]
+	raw: u32,
+}
+
+fn main() {}
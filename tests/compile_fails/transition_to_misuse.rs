@@ -0,0 +1,28 @@
+use dissolve_derive::Dissolve;
+
+struct Done {
+	id: u64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(transition_to = "Done")]
+struct WithoutVia {
+	id: u64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(transition_to = "Done", via(id, missing))]
+struct UnknownField {
+	id: u64,
+}
+
+#[derive(Dissolve)]
+#[dissolve(transition_to = "Done", via(id))]
+struct SkippedField {
+	#[dissolved(skip)]
+	id: u64,
+
+	name: String,
+}
+
+fn main() {}
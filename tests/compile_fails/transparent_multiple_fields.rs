@@ -0,0 +1,12 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(transparent)]
+struct Handle {
+	raw: u64,
+	generation: u32,
+	#[dissolved(skip)]
+	owner: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct TupleRenameWith(#[dissolved(rename_with = "upper")] String);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+union Bits {
+	int: u32,
+	float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(move_strategy = "clone")]
+struct UnknownMoveStrategy {
+	field: String,
+}
+
+fn main() {}
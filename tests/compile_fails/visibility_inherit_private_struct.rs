@@ -0,0 +1,19 @@
+mod inner {
+	use dissolve_derive::Dissolve;
+
+	#[derive(Dissolve)]
+	#[dissolve(visibility = "inherit")]
+	struct Ticket {
+		id: u64,
+	}
+
+	pub fn dissolve_ticket() {
+		let _ = Ticket { id: 7 }.dissolve();
+	}
+}
+
+fn main() {
+	inner::dissolve_ticket();
+
+	let _ = inner::TicketDissolved { id: 7 };
+}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(visibility = "pub(super::super)")]
+struct Nested {
+	value: i32,
+}
+
+fn main() {}
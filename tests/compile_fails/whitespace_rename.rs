@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct WhitespaceRename {
+	#[dissolved(rename = "  ")]
+	field: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+#[dissolve(snapshot)]
+struct Reading {
+	#[dissolved(with = "u32::to_string", ty = "String")]
+	id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve)]
+struct Reading {
+	#[dissolved(with = "u32::to_string")]
+	id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//! Default field values are unstable, run with
+//! `RUSTFLAGS="--cfg dissolve_nightly" cargo +nightly test --test default_field_values`.
+//!
+//! The syntax is rejected by the parser on stable, so the tests live in a module that is only
+//! loaded with the `dissolve_nightly` cfg.
+
+#![cfg_attr(dissolve_nightly, feature(default_field_values))]
+#![allow(dead_code)]
+
+#[cfg(dissolve_nightly)]
+#[path = "default_field_values/structs.rs"]
+mod structs;
@@ -0,0 +1,97 @@
+use std::collections::BTreeMap;
+
+use dissolve_derive::Dissolve;
+
+#[test]
+fn test_default_field_values_are_ignored() {
+	#[derive(Dissolve)]
+	struct WithDefaults {
+		retries: u32 = 3,
+
+		#[dissolved(rename = "endpoint")]
+		url: String = String::new(),
+
+		#[dissolved(skip)]
+		headers: Vec<(String, Vec<u8>)> = Vec::<(String, Vec<u8>)>::new(),
+
+		// The `,` in the turbofish comes after the `>` closing `Vec<u8>`
+		map: BTreeMap<Vec<u8>, u8> = BTreeMap::<Vec<u8>, u8>::new(),
+
+		limit: usize = <u8>::MAX as usize + 1,
+	}
+
+	// Arrange
+	let s = WithDefaults { url: "https://example.com".into(), .. };
+
+	// Act
+	let WithDefaultsDissolved { retries, endpoint, map, limit } = s.dissolve();
+
+	// Assert
+	assert_eq!(retries, 3);
+	assert_eq!(endpoint, "https://example.com");
+	assert!(map.is_empty());
+	assert_eq!(limit, 256);
+}
+
+#[test]
+fn test_default_field_values_with_generics() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct GenericDefaults<T> {
+		value: T,
+		callback: fn(u8) -> u8 = identity,
+		flag: bool = 1 < 2,
+	}
+
+	fn identity(x: u8) -> u8 {
+		x
+	}
+
+	// Arrange
+	let s = GenericDefaults { value: 'x', .. };
+
+	// Act
+	let dissolved = s.dissolve();
+	let callback = dissolved.callback;
+	let recomposed = dissolved.into_original();
+
+	// Assert
+	assert_eq!(recomposed.value, 'x');
+	assert_eq!(callback(7), 7);
+	assert!(recomposed.flag);
+}
+
+#[test]
+fn test_default_field_values_with_brackets_braces_and_arrows() {
+	const A: usize = 1;
+	const B: usize = 2;
+
+	#[derive(Debug, PartialEq)]
+	struct Foo<const N: usize>;
+
+	#[derive(Dissolve)]
+	struct Shapes<T, const N: usize> {
+		bytes: [u8; N] = [0; N],
+		sum: Foo<{ A + B }> = Foo,
+		make: fn() -> T,
+		fallback: Option<fn() -> T> = None,
+		count: usize = 2,
+	}
+
+	fn seven() -> u8 {
+		7
+	}
+
+	// Arrange
+	let s = Shapes::<u8, 2> { make: seven, .. };
+
+	// Act
+	let ShapesDissolved { bytes, sum, make, fallback, count } = s.dissolve();
+
+	// Assert
+	assert_eq!(bytes, [0, 0]);
+	assert_eq!(sum, Foo::<3>);
+	assert_eq!(make(), 7);
+	assert!(fallback.is_none());
+	assert_eq!(count, 2);
+}
@@ -2,7 +2,7 @@
 
 use core::f64;
 
-use dissolve_derive::Dissolve;
+use dissolve_derive::{Dissolve, dissolve_for};
 
 #[test]
 fn test_basic_dissolve() {
@@ -97,6 +97,41 @@ fn test_complex_struct() {
 	assert_eq!(email.as_deref(), Some("dave@example.com"));
 }
 
+#[test]
+fn test_dissolve_ufcs() {
+	#[derive(Dissolve)]
+	struct Connection {
+		socket: String,
+		retries: u32,
+	}
+
+	// Arrange
+	let conn = Connection { socket: "127.0.0.1:80".into(), retries: 3 };
+
+	// Act
+	let ConnectionDissolved { socket, retries } = Connection::dissolve(conn);
+
+	// Assert
+	assert_eq!(socket, "127.0.0.1:80");
+	assert_eq!(retries, 3);
+}
+
+#[test]
+fn test_dissolve_ufcs_as_fn_item() {
+	#[derive(Dissolve)]
+	struct Wrapper<T>(T, #[dissolved(skip)] u8);
+
+	// Arrange
+	let wrappers = vec![Wrapper("a", 0), Wrapper("b", 1)];
+
+	// Act
+	let values: Vec<_> =
+		wrappers.into_iter().map(Wrapper::dissolve).map(|(value,)| value).collect();
+
+	// Assert
+	assert_eq!(values, ["a", "b"]);
+}
+
 #[test]
 fn test_tuple_struct() {
 	#[derive(Dissolve)]
@@ -128,6 +163,63 @@ fn test_single_field_tuple_after_skip() {
 	assert_eq!(field_0, 100);
 }
 
+#[test]
+fn test_tuple_skip_in_middle_moves_original_indices() {
+	use std::mem::ManuallyDrop;
+
+	// All fields share a type, so reading the wrong index would still compile
+	#[derive(Dissolve)]
+	struct ByField(u8, #[dissolved(skip)] u8, u8, u8);
+
+	#[derive(Dissolve)]
+	#[dissolve(move_strategy = "destructure")]
+	struct ByDestructure(u8, #[dissolved(skip)] u8, u8, u8);
+
+	#[derive(Dissolve)]
+	#[dissolve(manually_drop)]
+	struct ByManuallyDrop(u8, #[dissolved(skip)] u8, u8, u8);
+
+	// Arrange
+	let by_field = ByField(10, 11, 12, 13);
+	let by_destructure = ByDestructure(10, 11, 12, 13);
+	let by_manually_drop = ByManuallyDrop(10, 11, 12, 13);
+
+	// Act
+	let from_field = by_field.dissolve();
+	let from_destructure = by_destructure.dissolve();
+	let (first, second, third) = by_manually_drop.dissolve();
+
+	// Assert
+	assert_eq!(from_field, (10, 12, 13));
+	assert_eq!(from_destructure, (10, 12, 13));
+	assert_eq!(
+		(
+			ManuallyDrop::into_inner(first),
+			ManuallyDrop::into_inner(second),
+			ManuallyDrop::into_inner(third)
+		),
+		(10, 12, 13)
+	);
+}
+
+#[test]
+fn test_array_skip_in_middle_uses_original_indices() {
+	#[derive(Dissolve)]
+	#[dissolve(array, snapshot)]
+	struct Channels(u8, #[dissolved(skip)] u8, u8, u8);
+
+	// Arrange
+	let channels = Channels(10, 11, 12, 13);
+
+	// Act
+	let snapshot = channels.snapshot();
+	let dissolved = channels.dissolve();
+
+	// Assert
+	assert_eq!(snapshot, [10, 12, 13]);
+	assert_eq!(dissolved, [10, 12, 13]);
+}
+
 #[test]
 fn test_nested_types() {
 	use std::collections::HashMap;
@@ -280,6 +372,139 @@ mod test_pub_super_visibility {
 	}
 }
 
+mod test_pub_in_path_visibility {
+	mod outer {
+		pub mod inner {
+			use dissolve_derive::Dissolve;
+
+			#[derive(Dissolve)]
+			#[dissolve(visibility = "pub(in super::super)")]
+			pub struct VisibilityInSuperSuper {
+				pub value: i32,
+			}
+
+			#[derive(Dissolve)]
+			#[dissolve(visibility = "pub(in crate::test_pub_in_path_visibility)")]
+			pub struct VisibilityInCratePath {
+				pub name: String,
+			}
+
+			#[derive(Dissolve)]
+			#[dissolve(visibility = "pub(in self)")]
+			pub struct VisibilityInSelf {
+				pub flag: bool,
+			}
+
+			#[test]
+			fn test_visibility_pub_in_self() {
+				// Arrange
+				let s = VisibilityInSelf { flag: true };
+
+				// Act
+				let VisibilityInSelfDissolved { flag } = s.dissolve();
+
+				// Assert
+				assert!(flag);
+			}
+		}
+	}
+
+	use outer::inner::{
+		VisibilityInCratePath, VisibilityInCratePathDissolved, VisibilityInSuperSuper,
+		VisibilityInSuperSuperDissolved,
+	};
+
+	#[test]
+	fn test_visibility_pub_in_super_super() {
+		// Arrange
+		let s = VisibilityInSuperSuper { value: 7 };
+
+		// Act
+		let VisibilityInSuperSuperDissolved { value } = s.dissolve();
+
+		// Assert
+		assert_eq!(value, 7);
+	}
+
+	#[test]
+	fn test_visibility_pub_in_crate_path() {
+		// Arrange
+		let s = VisibilityInCratePath { name: "nested".into() };
+
+		// Act
+		let VisibilityInCratePathDissolved { name } = s.dissolve();
+
+		// Assert
+		assert_eq!(name, "nested");
+	}
+}
+
+mod test_inherit_field_vis {
+	mod inner {
+		use dissolve_derive::Dissolve;
+
+		#[derive(Dissolve)]
+		#[dissolve(inherit_field_vis)]
+		pub struct Account {
+			pub id: u64,
+			pub(super) name: String,
+			secret: String,
+		}
+
+		impl Account {
+			pub fn new() -> Self {
+				Self { id: 1, name: "erin".into(), secret: "hunter2".into() }
+			}
+		}
+
+		pub fn secret_of(dissolved: &AccountDissolved) -> &str {
+			&dissolved.secret
+		}
+	}
+
+	#[test]
+	fn test_inherit_field_vis() {
+		// Arrange
+		let account = inner::Account::new();
+
+		// Act
+		let dissolved = account.dissolve();
+
+		// Assert
+		assert_eq!(dissolved.id, 1);
+		assert_eq!(dissolved.name, "erin");
+		assert_eq!(inner::secret_of(&dissolved), "hunter2");
+	}
+}
+
+mod test_visibility_inherit {
+	mod inner {
+		use dissolve_derive::Dissolve;
+
+		#[derive(Dissolve)]
+		#[dissolve(visibility = "inherit")]
+		pub(super) struct Ticket {
+			id: u64,
+		}
+
+		pub(super) fn ticket() -> Ticket {
+			Ticket { id: 7 }
+		}
+	}
+
+	#[test]
+	fn test_visibility_inherit() {
+		// Arrange
+		let ticket = inner::ticket();
+
+		// Act
+		let inner::TicketDissolved { id } = ticket.dissolve();
+
+		// Assert
+		assert_eq!(id, 7);
+	}
+}
+
 #[test]
 fn test_visibility_pub_self() {
 	#[derive(Dissolve)]
@@ -437,3 +662,3014 @@ fn test_default_pub_visibility_for_dissolved_struct() {
 	// Assert
 	assert_eq!(field, 99);
 }
+
+#[test]
+fn test_recompose_into_original() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct Recomposable {
+		#[dissolved(rename = "user_id")]
+		id: u64,
+
+		#[dissolved(skip)]
+		token: String,
+
+		name: String,
+
+		#[dissolved(skip)]
+		attempts: u8,
+	}
+
+	// Arrange
+	let s = Recomposable { id: 1, token: "old".into(), name: "erin".into(), attempts: 3 };
+
+	// Act
+	let mut dissolved = s.dissolve();
+	dissolved.user_id = 2;
+	let Recomposable { id, token, name, attempts } = dissolved.into_original("new".into(), 0);
+
+	// Assert
+	assert_eq!(id, 2);
+	assert_eq!(token, "new");
+	assert_eq!(name, "erin");
+	assert_eq!(attempts, 0);
+}
+
+#[test]
+fn test_recompose_renamed_skipped_field() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct Credentials {
+		user: String,
+
+		#[dissolved(skip, rename = "new_secret")]
+		secret: String,
+	}
+
+	// Arrange
+	let credentials = Credentials { user: "erin".into(), secret: "old".into() };
+
+	// Act
+	let dissolved = credentials.dissolve();
+	let Credentials { user, secret } = dissolved.into_original("new".into());
+
+	// Assert
+	assert_eq!(user, "erin");
+	assert_eq!(secret, "new");
+}
+
+#[test]
+fn test_recompose_many_skipped_fields() {
+	// `into_original` takes more arguments than clippy allows by default, which must not warn
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	#[allow(clippy::struct_excessive_bools)]
+	struct Options {
+		verbose: bool,
+
+		#[dissolved(skip)]
+		color: bool,
+		#[dissolved(skip)]
+		quiet: bool,
+		#[dissolved(skip)]
+		force: bool,
+		#[dissolved(skip)]
+		retries: u8,
+		#[dissolved(skip)]
+		timeout: u16,
+		#[dissolved(skip)]
+		depth: u32,
+		#[dissolved(skip)]
+		jobs: usize,
+	}
+
+	// Arrange
+	let options = Options {
+		verbose: true,
+		color: false,
+		quiet: false,
+		force: false,
+		retries: 0,
+		timeout: 0,
+		depth: 0,
+		jobs: 0,
+	};
+
+	// Act
+	let options = options.dissolve().into_original(true, true, true, 1, 2, 3, 4);
+
+	// Assert
+	assert!(options.verbose && options.color && options.quiet && options.force);
+	assert_eq!(
+		(
+			options.retries,
+			options.timeout,
+			options.depth,
+			options.jobs
+		),
+		(1, 2, 3, 4)
+	);
+}
+
+#[test]
+fn test_recompose_with_generics() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct GenericRecompose<T: Clone> {
+		value: T,
+		count: usize,
+	}
+
+	// Arrange
+	let s = GenericRecompose { value: vec![1, 2], count: 2 };
+
+	// Act
+	let GenericRecompose { value, count } = s.dissolve().into_original();
+
+	// Assert
+	assert_eq!(value, vec![1, 2]);
+	assert_eq!(count, 2);
+}
+
+#[test]
+fn test_keep_for_rebuild_round_trip() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct Session {
+		user_id: u64,
+
+		#[dissolved(skip, keep_for_rebuild)]
+		token: String,
+
+		#[dissolved(skip)]
+		attempts: u8,
+	}
+
+	// Arrange
+	let s = Session { user_id: 1, token: "secret".into(), attempts: 3 };
+
+	// Act
+	let (mut dissolved, carrier) = s.dissolve();
+	dissolved.user_id = 2;
+	let Session { user_id, token, attempts } = dissolved.into_original(carrier, 0);
+
+	// Assert
+	assert_eq!(user_id, 2);
+	assert_eq!(token, "secret");
+	assert_eq!(attempts, 0);
+}
+
+#[test]
+fn test_keep_for_rebuild_with_pruned_generics() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose, prune_generics, move_strategy = "destructure")]
+	struct Tagged<T, K> {
+		value: T,
+
+		#[dissolved(skip, keep_for_rebuild)]
+		key: K,
+	}
+
+	// Arrange
+	let s = Tagged { value: 1u8, key: "k" };
+
+	// Act
+	let (dissolved, carrier): (TaggedDissolved<u8>, TaggedCarrier<u8, &str>) = s.dissolve();
+	let Tagged { value, key } = dissolved.into_original(carrier);
+
+	// Assert
+	assert_eq!(value, 1);
+	assert_eq!(key, "k");
+}
+
+#[test]
+fn test_keep_returns_skipped_fields() {
+	#[derive(Dissolve)]
+	struct Request {
+		path: String,
+
+		#[dissolved(skip, keep)]
+		headers: Vec<(String, String)>,
+
+		#[dissolved(skip)]
+		retries: u8,
+	}
+
+	// Arrange
+	let r = Request {
+		path: "/index".into(),
+		headers: vec![("accept".into(), "text/html".into())],
+		retries: 3,
+	};
+
+	// Act
+	let (RequestDissolved { path }, RequestCarrier { headers }) = r.dissolve();
+
+	// Assert
+	assert_eq!(path, "/index");
+	assert_eq!(
+		headers,
+		vec![("accept".to_string(), "text/html".to_string())]
+	);
+}
+
+#[test]
+fn test_keep_round_trip_with_recompose() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct Session {
+		user_id: u64,
+
+		#[dissolved(skip, keep)]
+		expires_at: u64,
+
+		#[dissolved(skip, keep_for_rebuild)]
+		token: String,
+	}
+
+	// Arrange
+	let s = Session { user_id: 1, expires_at: 60, token: "secret".into() };
+
+	// Act
+	let (dissolved, carrier) = s.dissolve();
+	let expires_at = carrier.expires_at;
+	let Session { user_id, expires_at: rebuilt_expires_at, token } =
+		dissolved.into_original(carrier);
+
+	// Assert
+	assert_eq!(user_id, 1);
+	assert_eq!(expires_at, 60);
+	assert_eq!(rebuilt_expires_at, 60);
+	assert_eq!(token, "secret");
+}
+
+#[test]
+fn test_default_resets_skipped_fields_on_recompose() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct RequestBuilder {
+		pub url: String,
+		pub retries: u32,
+
+		#[dissolved(skip, default)]
+		validated: bool,
+
+		#[dissolved(skip, default = "Vec::with_capacity(4)")]
+		headers: Vec<String>,
+
+		#[dissolved(skip, rename = "trace_id")]
+		id: u64,
+	}
+
+	// Arrange
+	let builder = RequestBuilder {
+		url: "http://localhost".into(),
+		retries: 1,
+		validated: true,
+		headers: vec!["accept: */*".into()],
+		id: 7,
+	};
+
+	// Act
+	let mut dissolved = builder.dissolve();
+	dissolved.url.push_str("/health");
+	let rebuilt = dissolved.into_original(8);
+
+	// Assert
+	assert_eq!(rebuilt.url, "http://localhost/health");
+	assert_eq!(rebuilt.retries, 1);
+	assert!(!rebuilt.validated);
+	assert!(rebuilt.headers.is_empty());
+	assert_eq!(rebuilt.headers.capacity(), 4);
+	assert_eq!(rebuilt.id, 8);
+}
+
+struct DropCounter<'a>(&'a std::cell::Cell<u32>);
+
+impl Drop for DropCounter<'_> {
+	fn drop(&mut self) {
+		self.0.set(self.0.get() + 1);
+	}
+}
+
+#[test]
+fn test_manually_drop_bypasses_drop() {
+	use std::{cell::Cell, mem::ManuallyDrop};
+
+	#[derive(Dissolve)]
+	#[dissolve(manually_drop)]
+	struct Guarded<'a> {
+		kept: DropCounter<'a>,
+
+		#[dissolved(skip)]
+		skipped: DropCounter<'a>,
+
+		container_drops: &'a Cell<u32>,
+	}
+
+	impl Drop for Guarded<'_> {
+		fn drop(&mut self) {
+			self.container_drops.set(self.container_drops.get() + 1);
+		}
+	}
+
+	// Arrange
+	let kept_drops = Cell::new(0);
+	let skipped_drops = Cell::new(0);
+	let container_drops = Cell::new(0);
+
+	let s = Guarded {
+		kept: DropCounter(&kept_drops),
+		skipped: DropCounter(&skipped_drops),
+		container_drops: &container_drops,
+	};
+
+	// Act
+	let GuardedDissolved { kept, container_drops: dissolved_container_drops } = s.dissolve();
+
+	// Assert
+	assert_eq!(container_drops.get(), 0);
+	assert_eq!(skipped_drops.get(), 1);
+	assert_eq!(kept_drops.get(), 0);
+	assert_eq!(dissolved_container_drops.get(), 0);
+
+	drop(ManuallyDrop::into_inner(kept));
+	assert_eq!(kept_drops.get(), 1);
+}
+
+#[test]
+fn test_manually_drop_tuple_struct() {
+	use std::{cell::Cell, mem::ManuallyDrop};
+
+	#[derive(Dissolve)]
+	#[dissolve(manually_drop)]
+	struct GuardedTuple<'a>(
+		DropCounter<'a>,
+		#[dissolved(skip)] DropCounter<'a>,
+		&'a Cell<bool>,
+	);
+
+	impl Drop for GuardedTuple<'_> {
+		fn drop(&mut self) {
+			self.2.set(true);
+		}
+	}
+
+	// Arrange
+	let kept_drops = Cell::new(0);
+	let skipped_drops = Cell::new(0);
+	let container_dropped = Cell::new(false);
+
+	let t = GuardedTuple(
+		DropCounter(&kept_drops),
+		DropCounter(&skipped_drops),
+		&container_dropped,
+	);
+
+	// Act
+	let (kept, _) = t.dissolve();
+	drop(ManuallyDrop::into_inner(kept));
+
+	// Assert
+	assert!(!container_dropped.get());
+	assert_eq!(skipped_drops.get(), 1);
+	assert_eq!(kept_drops.get(), 1);
+}
+
+#[test]
+fn test_manually_drop_with_recompose() {
+	#[derive(Dissolve)]
+	#[dissolve(manually_drop, recompose)]
+	struct Resource {
+		handle: u32,
+
+		#[dissolved(skip)]
+		name: String,
+	}
+
+	impl Drop for Resource {
+		fn drop(&mut self) {}
+	}
+
+	// Arrange
+	let s = Resource { handle: 3, name: "before".into() };
+
+	// Act
+	let restored = s.dissolve().into_original("after".into());
+
+	// Assert
+	assert_eq!(restored.handle, 3);
+	assert_eq!(restored.name, "after");
+}
+
+#[test]
+fn test_move_strategy_destructure() {
+	#[derive(Dissolve)]
+	#[dissolve(move_strategy = "destructure")]
+	struct Destructured {
+		#[dissolved(rename = "label")]
+		name: String,
+
+		#[dissolved(skip)]
+		cache: Vec<u8>,
+
+		count: usize,
+	}
+
+	// Arrange
+	let s = Destructured { name: "frank".into(), cache: vec![0; 4], count: 4 };
+
+	// Act
+	let DestructuredDissolved { label, count } = s.dissolve();
+
+	// Assert
+	assert_eq!(label, "frank");
+	assert_eq!(count, 4);
+}
+
+#[test]
+fn test_move_strategy_destructure_tuple_struct() {
+	#[derive(Dissolve)]
+	#[dissolve(move_strategy = "destructure")]
+	struct DestructuredTuple(String, #[dissolved(skip)] bool, u8);
+
+	// Arrange
+	let t = DestructuredTuple("tuple".into(), true, 7);
+
+	// Act
+	let (field_0, field_2) = t.dissolve();
+
+	// Assert
+	assert_eq!(field_0, "tuple");
+	assert_eq!(field_2, 7);
+}
+
+#[test]
+fn test_move_strategy_field_explicit() {
+	#[derive(Dissolve)]
+	#[dissolve(move_strategy = "field")]
+	struct ExplicitField {
+		value: i32,
+	}
+
+	// Arrange
+	let s = ExplicitField { value: 5 };
+
+	// Act
+	let ExplicitFieldDissolved { value } = s.dissolve();
+
+	// Assert
+	assert_eq!(value, 5);
+}
+
+macro_rules! raw_keyword_field_tests {
+	($($test_name:ident: $field:ident),* $(,)?) => {
+		$(
+			#[test]
+			fn $test_name() {
+				#[derive(Dissolve)]
+				#[dissolve(recompose)]
+				struct RawKeyword {
+					$field: u32,
+
+					#[dissolved(rename = "renamed")]
+					other: u32,
+				}
+
+				#[derive(Dissolve)]
+				#[dissolve(move_strategy = "destructure")]
+				struct RawKeywordDestructured {
+					$field: u32,
+				}
+
+				// Arrange
+				let s = RawKeyword { $field: 1, other: 2 };
+				let d = RawKeywordDestructured { $field: 3 };
+
+				// Act
+				let RawKeywordDissolved { $field, renamed } = s.dissolve();
+				let recomposed = RawKeywordDissolved { $field, renamed }.into_original();
+				let RawKeywordDestructuredDissolved { $field: destructured } = d.dissolve();
+
+				// Assert
+				assert_eq!(recomposed.$field, 1);
+				assert_eq!(recomposed.other, 2);
+				assert_eq!(destructured, 3);
+			}
+		)*
+	};
+}
+
+raw_keyword_field_tests! {
+	test_raw_keyword_field_fn: r#fn,
+	test_raw_keyword_field_match: r#match,
+	test_raw_keyword_field_type: r#type,
+	test_raw_keyword_field_loop: r#loop,
+	test_raw_keyword_field_async: r#async,
+	test_raw_keyword_field_yield: r#yield,
+}
+
+#[test]
+fn test_unrenamed_raw_keyword_field() {
+	#[derive(Dissolve)]
+	#[dissolve(only("type", "r#match"), into_tuple, doc_provenance)]
+	struct Token {
+		r#type: String,
+		r#match: usize,
+		r#loop: bool,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(manually_drop)]
+	struct Manual {
+		r#type: String,
+
+		#[dissolved(skip)]
+		r#ref: String,
+	}
+
+	#[derive(Dissolve)]
+	enum Node {
+		Leaf { r#type: u8 },
+	}
+
+	// Arrange
+	let token = Token { r#type: "ident".into(), r#match: 3, r#loop: false };
+	let manual = Manual { r#type: "manual".into(), r#ref: "dropped".into() };
+
+	// Act
+	let (r#type, r#match) = token.dissolve().into();
+	let ManualDissolved { r#type: manual_type } = manual.dissolve();
+	let NodeDissolved::Leaf { r#type: leaf_type } = (Node::Leaf { r#type: 9 }).dissolve();
+
+	// Assert
+	assert_eq!(r#type, "ident");
+	assert_eq!(r#match, 3);
+	assert_eq!(*manual_type, "manual");
+	assert_eq!(leaf_type, 9);
+}
+
+#[test]
+fn test_rename_to_keyword() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct RenameToKeyword {
+		#[dissolved(rename = "type")]
+		kind: String,
+
+		#[dissolved(rename = "r#match")]
+		pattern: String,
+
+		#[dissolved(rename = "plain")]
+		r#fn: u8,
+	}
+
+	// Arrange
+	let s = RenameToKeyword { kind: "user".into(), pattern: "*".into(), r#fn: 1 };
+
+	// Act
+	let RenameToKeywordDissolved { r#type, r#match, plain } = s.dissolve();
+	let recomposed = RenameToKeywordDissolved { r#type, r#match, plain }.into_original();
+
+	// Assert
+	assert_eq!(recomposed.kind, "user");
+	assert_eq!(recomposed.pattern, "*");
+	assert_eq!(recomposed.r#fn, 1);
+}
+
+#[test]
+fn test_rename_with_transforms() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose)]
+	struct RawBuffers {
+		#[dissolved(rename_with = "strip_prefix:raw_")]
+		raw_header: Vec<u8>,
+
+		#[dissolved(rename_with = "strip_prefix:raw_ | suffix:_bytes")]
+		raw_body: Vec<u8>,
+
+		#[dissolved(rename_with = "prefix:total_")]
+		len: usize,
+
+		#[dissolved(rename_with = "prefix:CRC_ | lower")]
+		checksum: u32,
+
+		// Names that are not snake case do not make the generated code warn
+		#[dissolved(rename_with = "upper")]
+		flags: u8,
+
+		#[dissolved(rename_with = "strip_suffix:_kind")]
+		type_kind: u8,
+	}
+
+	// Arrange
+	let s = RawBuffers {
+		raw_header: vec![1],
+		raw_body: vec![2, 3],
+		len: 3,
+		checksum: 42,
+		flags: 1,
+		type_kind: 0,
+	};
+
+	// Act
+	let RawBuffersDissolved { header, body_bytes, total_len, crc_checksum, FLAGS: flags, r#type } =
+		s.dissolve();
+	let recomposed =
+		RawBuffersDissolved { header, body_bytes, total_len, crc_checksum, FLAGS: flags, r#type }
+			.into_original();
+
+	// Assert
+	assert_eq!(recomposed.raw_header, vec![1]);
+	assert_eq!(recomposed.raw_body, vec![2, 3]);
+	assert_eq!(recomposed.len, 3);
+	assert_eq!(recomposed.checksum, 42);
+	assert_eq!(recomposed.flags, 1);
+	assert_eq!(recomposed.type_kind, 0);
+}
+
+#[test]
+fn test_rename_takes_precedence_over_rename_with() {
+	#[derive(Dissolve)]
+	struct Limits {
+		// The transform would fail on `max`, but it is not applied
+		#[dissolved(rename_with = "strip_prefix:raw_", rename = "ceiling")]
+		max: u32,
+
+		#[dissolved(rename = "floor")]
+		#[dissolved(rename_with = "prefix:lowest_")]
+		min: u32,
+
+		#[dissolved(rename_with = "upper", rename = "average")]
+		mean: u32,
+
+		#[dissolved(rename_with = "upper")]
+		step: u32,
+	}
+
+	// Arrange
+	let limits = Limits { max: 10, min: 1, mean: 5, step: 2 };
+
+	// Act
+	let LimitsDissolved { ceiling, floor, average, STEP: step } = limits.dissolve();
+
+	// Assert
+	assert_eq!((ceiling, floor, average, step), (10, 1, 5, 2));
+}
+
+#[test]
+fn test_only_allowlist() {
+	#[derive(Dissolve)]
+	#[dissolve(only("socket", "buffer"))]
+	struct Connection {
+		socket: String,
+		buffer: Vec<u8>,
+		retries: u32,
+		last_error: Option<String>,
+
+		#[dissolved(skip)]
+		handle: u64,
+	}
+
+	// Arrange
+	let conn = Connection {
+		socket: "127.0.0.1:80".into(),
+		buffer: vec![1, 2, 3],
+		retries: 3,
+		last_error: None,
+		handle: 7,
+	};
+
+	// Act
+	let ConnectionDissolved { socket, buffer } = conn.dissolve();
+
+	// Assert
+	assert_eq!(socket, "127.0.0.1:80");
+	assert_eq!(buffer, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_only_allowlist_with_rename_and_recompose() {
+	#[derive(Dissolve)]
+	#[dissolve(only("r#type"), only("value"), recompose)]
+	struct Tagged {
+		#[dissolved(rename = "kind")]
+		r#type: String,
+		value: i32,
+		cached: bool,
+	}
+
+	// Arrange
+	let tagged = Tagged { r#type: "int".into(), value: 42, cached: true };
+
+	// Act
+	let dissolved = tagged.dissolve();
+	let recomposed = dissolved.into_original(false);
+
+	// Assert
+	assert_eq!(recomposed.r#type, "int");
+	assert_eq!(recomposed.value, 42);
+	assert!(!recomposed.cached);
+}
+
+#[test]
+fn test_only_allowlist_with_rename_all() {
+	#[derive(Dissolve)]
+	#[dissolve(only("host"), rename_all = "prefix:remote_")]
+	struct Endpoint {
+		host: String,
+		port: u16,
+	}
+
+	// Arrange
+	let endpoint = Endpoint { host: "localhost".into(), port: 80 };
+
+	// Act
+	let EndpointDissolved { remote_host } = endpoint.dissolve();
+
+	// Assert
+	assert_eq!(remote_host, "localhost");
+}
+
+#[test]
+fn test_only_public() {
+	#[derive(Dissolve)]
+	#[dissolve(only_public, rename_all = "prefix:user_")]
+	pub struct Account {
+		pub name: String,
+		pub(crate) email: String,
+		password_hash: String,
+
+		#[dissolved(skip, keep)]
+		login_count: u32,
+
+		#[dissolved(skip)]
+		pub session: Option<String>,
+	}
+
+	// Arrange
+	let account = Account {
+		name: "ferris".into(),
+		email: "ferris@example.com".into(),
+		password_hash: "hunter2".into(),
+		login_count: 3,
+		session: None,
+	};
+
+	// Act
+	let (AccountDissolved { user_name, user_email }, carrier) = account.dissolve();
+
+	// Assert
+	assert_eq!(user_name, "ferris");
+	assert_eq!(user_email, "ferris@example.com");
+	assert_eq!(carrier.login_count, 3);
+}
+
+#[test]
+fn test_into_tuple() {
+	#[derive(Dissolve)]
+	#[dissolve(into_tuple)]
+	struct Connection {
+		socket: String,
+
+		#[dissolved(skip)]
+		retries: u32,
+
+		#[dissolved(rename = "bytes")]
+		buffer: Vec<u8>,
+	}
+
+	// Arrange
+	let conn = Connection { socket: "127.0.0.1:80".into(), retries: 3, buffer: vec![1, 2] };
+
+	// Act
+	let (socket, bytes): (String, Vec<u8>) = conn.dissolve().into();
+
+	// Assert
+	assert_eq!(socket, "127.0.0.1:80");
+	assert_eq!(bytes, vec![1, 2]);
+}
+
+#[test]
+fn test_into_tuple_single_field_with_generics() {
+	#[derive(Dissolve)]
+	#[dissolve(into_tuple)]
+	struct Wrapper<'a, T: Clone> {
+		value: &'a T,
+
+		#[dissolved(skip)]
+		tag: u8,
+	}
+
+	// Arrange
+	let value = 1729;
+	let wrapper = Wrapper { value: &value, tag: 0 };
+
+	// Act
+	let (inner,) = <(&i32,)>::from(wrapper.dissolve());
+
+	// Assert
+	assert_eq!(*inner, 1729);
+}
+
+#[test]
+fn test_from_dissolved() {
+	#[derive(Debug, PartialEq, Dissolve)]
+	#[dissolve(from_dissolved)]
+	struct Endpoint<'a, T> {
+		#[dissolved(rename = "address")]
+		host: &'a str,
+
+		port: u16,
+
+		meta: T,
+	}
+
+	// Arrange
+	let endpoint = Endpoint { host: "localhost", port: 8080, meta: vec![1u8] };
+
+	// Act
+	let mut dissolved = endpoint.dissolve();
+	dissolved.port += 1;
+	let rebuilt = Endpoint::from(dissolved);
+
+	// Assert
+	assert_eq!(
+		rebuilt,
+		Endpoint { host: "localhost", port: 8081, meta: vec![1u8] }
+	);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_keep_attrs() {
+	#[derive(Dissolve)]
+	#[dissolve(keep_attrs(doc), keep_attrs(deprecated, allow))]
+	struct Config {
+		/// Address to bind to
+		#[deprecated = "use `endpoint` instead"]
+		address: String,
+
+		#[allow(clippy::struct_field_names)]
+		endpoint: String,
+	}
+
+	// Arrange
+	let config = Config { address: "0.0.0.0".into(), endpoint: "localhost:80".into() };
+
+	// Act
+	let ConfigDissolved { address, endpoint } = config.dissolve();
+
+	// Assert
+	assert_eq!(address, "0.0.0.0");
+	assert_eq!(endpoint, "localhost:80");
+}
+
+#[test]
+fn test_doc_provenance() {
+	#[derive(Dissolve)]
+	#[dissolve(doc_provenance)]
+	struct Account {
+		/// Unique identifier
+		#[dissolved(rename = "account_id")]
+		id: u64,
+
+		pub(crate) name: String,
+
+		pub email: String,
+	}
+
+	// Arrange
+	let account = Account { id: 7, name: "dana".into(), email: "dana@example.com".into() };
+
+	// Act
+	let AccountDissolved { account_id, name, email } = account.dissolve();
+
+	// Assert
+	assert_eq!(account_id, 7);
+	assert_eq!(name, "dana");
+	assert_eq!(email, "dana@example.com");
+}
+
+#[test]
+fn test_map_dissolved() {
+	#[derive(Dissolve)]
+	#[dissolve(map)]
+	struct Point {
+		x: i32,
+		y: i32,
+
+		#[dissolved(skip)]
+		label: String,
+	}
+
+	// Arrange
+	let point = Point { x: 3, y: 4, label: "p".into() };
+
+	// Act
+	let sum = point.map_dissolved(|PointDissolved { x, y }| x + y);
+
+	// Assert
+	assert_eq!(sum, 7);
+}
+
+#[test]
+fn test_map_dissolved_tuple_struct_with_generic_named_r() {
+	#[derive(Dissolve)]
+	#[dissolve(map)]
+	struct Pair<R>(R, R);
+
+	// Arrange
+	let pair = Pair("left".to_string(), "right".to_string());
+
+	// Act
+	let joined = pair.map_dissolved(|(left, right)| format!("{left}-{right}"));
+
+	// Assert
+	assert_eq!(joined, "left-right");
+}
+
+#[test]
+fn test_newtype() {
+	#[derive(Dissolve)]
+	#[dissolve(newtype, map)]
+	struct Meters(f64);
+
+	// Arrange
+	let distance = Meters(12.5);
+
+	// Act
+	let inner: f64 = Meters(3.0).dissolve();
+	let converted = f64::from(distance);
+	let doubled = Meters(2.0).map_dissolved(|value| value * 2.0);
+
+	// Assert
+	assert_eq!(inner, 3.0);
+	assert_eq!(converted, 12.5);
+	assert_eq!(doubled, 4.0);
+}
+
+#[test]
+fn test_newtype_generic_with_skipped_marker() {
+	#[derive(Dissolve)]
+	#[dissolve(newtype)]
+	struct Tagged<T, Tag>(T, #[dissolved(skip)] std::marker::PhantomData<Tag>);
+
+	#[derive(Dissolve)]
+	#[dissolve(newtype)]
+	struct Items<T>(Vec<T>);
+
+	struct UserId;
+
+	// Arrange
+	let tagged = Tagged::<u64, UserId>(42, std::marker::PhantomData);
+	let items = Items(vec![1, 2, 3]);
+
+	// Act
+	let id = tagged.dissolve();
+	let values = Vec::from(items);
+
+	// Assert
+	assert_eq!(id, 42);
+	assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_newtype_repr_transparent() {
+	#[derive(Dissolve)]
+	#[dissolve(newtype)]
+	#[repr(transparent)]
+	struct Meters(f64);
+
+	#[derive(Dissolve)]
+	#[dissolve(newtype, snapshot)]
+	#[repr(transparent)]
+	struct UserId<Tag> {
+		value: u64,
+
+		#[dissolved(skip)]
+		tag: std::marker::PhantomData<Tag>,
+	}
+
+	struct Admin;
+
+	// Arrange
+	let id = UserId::<Admin> { value: 7, tag: std::marker::PhantomData };
+
+	// Act
+	let meters: f64 = Meters(1.5).dissolve();
+	let copied = id.snapshot();
+	let value = u64::from(id);
+
+	// Assert
+	assert_eq!(meters, 1.5);
+	assert_eq!((copied, value), (7, 7));
+}
+
+#[test]
+fn test_prune_generics() {
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics)]
+	struct Cache<'a, K: Clone, V, const N: usize> {
+		keys: [K; N],
+
+		#[dissolved(skip)]
+		values: &'a [V],
+	}
+
+	// Arrange
+	let values = ["one".to_string(), "two".to_string()];
+	let cache = Cache { keys: [1u8, 2], values: &values };
+
+	// Act
+	let dissolved: CacheDissolved<u8, 2> = cache.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.keys, [1, 2]);
+}
+
+#[test]
+fn test_prune_generics_with_recompose() {
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics, recompose)]
+	struct Labeled<T, L: Into<String>> {
+		value: T,
+
+		#[dissolved(skip)]
+		label: L,
+	}
+
+	// Arrange
+	let labeled = Labeled { value: 3.5f32, label: "speed" };
+
+	// Act
+	let dissolved: LabeledDissolved<f32> = labeled.dissolve();
+	let recomposed = dissolved.into_original(String::from("velocity"));
+
+	// Assert
+	assert_eq!(recomposed.value, 3.5);
+	assert_eq!(recomposed.label, "velocity");
+}
+
+#[test]
+fn test_enum_with_mixed_variants() {
+	#[derive(Dissolve)]
+	#[dissolve(map, keep_attrs(doc, allow))]
+	enum Connection<T> {
+		/// Not connected yet
+		Idle,
+
+		Connecting(String, #[dissolved(skip)] u32),
+
+		Connected {
+			#[dissolved(rename = "address")]
+			peer: String,
+
+			session: T,
+
+			#[dissolved(skip)]
+			retries: u32,
+
+			#[allow(clippy::struct_field_names)]
+			r#type: u8,
+		},
+
+		Closed(#[dissolved(skip)] Option<String>),
+
+		Aborted {
+			#[dissolved(skip)]
+			reason: String,
+		},
+	}
+
+	// Arrange
+	let states = vec![
+		Connection::Idle,
+		Connection::Connecting("10.0.0.1".into(), 2),
+		Connection::Connected { peer: "10.0.0.2".into(), session: 7u64, retries: 1, r#type: 4 },
+		Connection::Closed(Some("bye".into())),
+		Connection::Aborted { reason: "timeout".into() },
+	];
+
+	// Act
+	let dissolved: Vec<_> = states.into_iter().map(Connection::dissolve).collect();
+	let summary =
+		Connection::<u64>::Connecting("10.0.0.3".into(), 0).map_dissolved(|state| match state {
+			ConnectionDissolved::Connecting(address) => address,
+			_ => unreachable!(),
+		});
+
+	// Assert
+	assert!(matches!(dissolved[0], ConnectionDissolved::Idle));
+	assert!(
+		matches!(&dissolved[1], ConnectionDissolved::Connecting(address) if address == "10.0.0.1")
+	);
+	assert!(matches!(
+		&dissolved[2],
+		ConnectionDissolved::Connected { address, session: 7, r#type: 4 } if address == "10.0.0.2"
+	));
+	assert!(matches!(dissolved[3], ConnectionDissolved::Closed()));
+	assert!(matches!(dissolved[4], ConnectionDissolved::Aborted {}));
+	assert_eq!(summary, "10.0.0.3");
+}
+
+#[test]
+fn test_enum_visibility() {
+	mod states {
+		use dissolve_derive::Dissolve;
+
+		#[derive(Dissolve)]
+		#[dissolve(visibility = "pub(super)")]
+		pub(super) enum Light {
+			Off,
+			On { brightness: u8 },
+		}
+	}
+
+	// Arrange
+	let light = states::Light::On { brightness: 80 };
+
+	// Act
+	let dissolved = light.dissolve();
+
+	// Assert
+	assert!(matches!(
+		dissolved,
+		states::LightDissolved::On { brightness: 80 }
+	));
+	assert!(matches!(
+		states::Light::Off.dissolve(),
+		states::LightDissolved::Off
+	));
+}
+
+#[test]
+fn test_skipped_generic_field_gets_marker() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose, into_tuple)]
+	struct S<'a, T, const N: usize> {
+		#[dissolved(skip)]
+		v: T,
+
+		#[dissolved(skip)]
+		items: &'a [u8; N],
+
+		name: String,
+	}
+
+	// Arrange
+	let items = [0u8; 3];
+	let s = S { v: 42u64, items: &items, name: "only".into() };
+
+	// Act
+	let SDissolved { name, _marker } = s.dissolve();
+	let recomposed =
+		SDissolved::<u64, 3> { name, _marker: std::marker::PhantomData }.into_original(7, &items);
+	let (name,) = <(String,)>::from(recomposed.dissolve());
+
+	// Assert
+	assert_eq!(name, "only");
+}
+
+#[test]
+fn test_lifetime_only_used_by_skipped_field() {
+	// The only field borrowing for `'a` is skipped: by default the dissolved struct keeps `'a`
+	// through its `_marker`, and `prune_generics` drops it
+	#[derive(Dissolve)]
+	struct Marked<'a> {
+		id: u8,
+
+		#[dissolved(skip)]
+		_name: &'a str,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics)]
+	struct Pruned<'a> {
+		id: u8,
+
+		#[dissolved(skip)]
+		_name: &'a str,
+	}
+
+	// Arrange
+	let name = String::from("borrowed");
+	let marked = Marked { id: 1, _name: &name };
+	let pruned = Pruned { id: 2, _name: &name };
+
+	// Act
+	let marked: MarkedDissolved<'_> = marked.dissolve();
+	let pruned: PrunedDissolved = pruned.dissolve();
+
+	// Assert
+	assert_eq!(marked.id, 1);
+	// Without `'a`, the pruned dissolved struct no longer borrows `name`
+	drop(name);
+	assert_eq!(pruned.id, 2);
+}
+
+#[test]
+fn test_skipped_fields_add_no_bounds() {
+	// Not `Clone`, `Send` or `Sync`: none of it should reach the dissolved structs
+	struct Handle(std::rc::Rc<u8>);
+
+	#[derive(Dissolve)]
+	#[dissolve(derive(Clone, Debug, PartialEq))]
+	struct Session {
+		id: u32,
+
+		#[dissolved(skip)]
+		_handle: Handle,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(derive(Clone, Debug, PartialEq))]
+	struct Marked<T> {
+		id: u32,
+
+		#[dissolved(skip)]
+		_local: T,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics, derive(Clone, Debug, PartialEq))]
+	struct Pruned<T> {
+		id: u32,
+
+		#[dissolved(skip)]
+		_local: T,
+	}
+
+	fn assert_send_sync<S: Send + Sync>(_: &S) {}
+
+	// Arrange
+	let session = Session { id: 1, _handle: Handle(std::rc::Rc::new(0)) };
+	let marked = Marked { id: 2, _local: std::rc::Rc::new(0u8) };
+	let pruned = Pruned { id: 3, _local: Handle(std::rc::Rc::new(0)) };
+
+	// Act
+	let session = session.dissolve();
+	let marked = marked.dissolve();
+	let pruned = pruned.dissolve();
+
+	// Assert
+	assert_eq!(session.clone(), session);
+	assert_send_sync(&session);
+	// The marker holds no `T`, but the derived `Clone` still asks for `T: Clone`
+	assert_eq!(marked.clone().id, 2);
+	assert_send_sync(&marked);
+	assert_eq!(pruned.clone(), PrunedDissolved { id: 3 });
+	assert_send_sync(&pruned);
+}
+
+#[test]
+fn test_marker_name_avoids_field_clash() {
+	#[derive(Dissolve)]
+	struct Tagged<T> {
+		_marker: u8,
+
+		#[dissolved(skip)]
+		tag: T,
+	}
+
+	// Arrange
+	let tagged = Tagged { _marker: 1, tag: "tag" };
+
+	// Act
+	let TaggedDissolved { _marker, _marker_ } = tagged.dissolve();
+
+	// Assert
+	assert_eq!(_marker, 1);
+}
+
+#[test]
+fn test_unsized_type_parameters() {
+	#[derive(Dissolve)]
+	#[dissolve(recompose, from_dissolved)]
+	struct Wrapper<T: ?Sized> {
+		boxed: Box<T>,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(recompose, prune_generics)]
+	struct Labeled<T: ?Sized, L>
+	where
+		L: ?Sized + std::fmt::Display,
+	{
+		boxed: Box<T>,
+
+		#[dissolved(skip)]
+		label: Box<L>,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(newtype)]
+	struct Handle<T: ?Sized>(Box<T>);
+
+	// Arrange
+	let wrapper: Wrapper<str> = Wrapper { boxed: "wrapped".into() };
+	let labeled: Labeled<[u8], str> = Labeled { boxed: Box::new([1, 2]), label: "two".into() };
+	let handle: Handle<dyn std::fmt::Debug> = Handle(Box::new(7));
+
+	// Act
+	let wrapper = Wrapper::from(wrapper.dissolve()).dissolve().into_original();
+	let labeled = labeled.dissolve().into_original::<str>("pair".into());
+	let handle = handle.dissolve();
+
+	// Assert
+	assert_eq!(&*wrapper.boxed, "wrapped");
+	assert_eq!(&*labeled.boxed, &[1, 2]);
+	assert_eq!(labeled.label.to_string(), "pair");
+	assert_eq!(format!("{handle:?}"), "7");
+}
+
+#[test]
+fn test_prune_generics_drops_where_predicates() {
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics)]
+	struct S<T, U>
+	where
+		U: Clone,
+	{
+		a: T,
+
+		#[dissolved(skip)]
+		b: U,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics, recompose)]
+	struct Partial<'a, 'b, T, U>
+	where
+		T: Clone + PartialEq<U> + 'b,
+		'a: 'b,
+		U: Default,
+	{
+		value: T,
+
+		#[dissolved(skip)]
+		other: &'a U,
+
+		#[dissolved(skip)]
+		borrowed: &'b str,
+	}
+
+	// Arrange
+	let s = S { a: 1u8, b: "skipped".to_string() };
+	let other = 2.0f64;
+	let partial = Partial { value: 1.0f64, other: &other, borrowed: "b" };
+
+	// Act
+	let SDissolved { a } = s.dissolve();
+	let dissolved: PartialDissolved<f64> = partial.dissolve();
+	let recomposed = dissolved.into_original(&other, "c");
+
+	// Assert
+	assert_eq!(a, 1);
+	assert_eq!(recomposed.value, 1.0);
+	assert_eq!(recomposed.borrowed, "c");
+}
+
+#[test]
+fn test_macro_generated_struct_with_foreign_attributes() {
+	macro_rules! define_record {
+		(
+			$(#[$struct_meta:meta])*
+			struct $name:ident {
+				$($(#[$field_meta:meta])* $field:ident: $ty:ty),* $(,)?
+			}
+		) => {
+			$(#[$struct_meta])*
+			struct $name {
+				$($(#[$field_meta])* $field: $ty),*
+			}
+		};
+	}
+
+	define_record! {
+		#[derive(Debug, Clone, PartialEq, Dissolve)]
+		#[repr(C)]
+		#[dissolve(visibility = "pub(crate)")]
+		struct Record {
+			#[doc = concat!("Identifier of the ", "record")]
+			#[rustfmt::skip]
+			id: u32,
+
+			#[cfg_attr(all(), dissolved(rename = "label"))]
+			#[allow(clippy::struct_field_names)]
+			name: Vec<String>,
+
+			#[doc(hidden)]
+			#[cfg_attr(any(), dissolved(rename = "never"))]
+			#[dissolved(skip)]
+			cache: Option<u64>,
+		}
+	}
+
+	// Arrange
+	let record = Record { id: 5, name: vec!["a".into()], cache: Some(1) };
+
+	// Act
+	let RecordDissolved { id, label } = record.clone().dissolve();
+
+	// Assert
+	assert_eq!(record, Record { id, name: label, cache: Some(1) });
+}
+
+#[test]
+fn test_array() {
+	#[derive(Dissolve)]
+	#[dissolve(array)]
+	struct Rgb(u8, u8, u8);
+
+	#[derive(Dissolve)]
+	#[dissolve(array, map)]
+	struct Labeled<T>(T, #[dissolved(skip)] &'static str, T);
+
+	// Arrange
+	let color = Rgb(255, 128, 0);
+	let labeled = Labeled(String::from("a"), "label", String::from("b"));
+
+	// Act
+	let channels: [u8; 3] = color.dissolve();
+	let joined = labeled.map_dissolved(|parts: [String; 2]| parts.concat());
+
+	// Assert
+	assert_eq!(channels, [255, 128, 0]);
+	assert_eq!(joined, "ab");
+}
+
+#[test]
+fn test_test_helpers_constructor() {
+	#[derive(Dissolve)]
+	#[cfg_attr(test, dissolve(test_helpers))]
+	struct Request<B> {
+		#[dissolved(rename = "url")]
+		uri: String,
+
+		#[dissolved(skip)]
+		body: B,
+
+		retries: u8,
+	}
+
+	// Arrange
+	let request = Request { uri: "/health".into(), body: vec![0u8], retries: 2 };
+
+	// Act
+	let dissolved = request.dissolve();
+	let built = RequestDissolved::<Vec<u8>>::new("/health".into(), 2);
+
+	// Assert
+	assert_eq!(built.url, dissolved.url);
+	assert_eq!(built.retries, dissolved.retries);
+}
+
+#[test]
+fn test_dissolve_for_foreign_struct() {
+	use std::ops::Range;
+
+	dissolve_for! {
+		#[dissolve(recompose)]
+		struct Range<Idx> {
+			#[dissolved(rename = "from")]
+			start: Idx,
+			end: Idx,
+		}
+	}
+
+	// Arrange
+	let range = 2..5;
+
+	// Act
+	let mut dissolved = dissolve_range(range);
+	dissolved.from = 3;
+	let range = dissolved.into_original();
+
+	// Assert
+	assert_eq!(range, 3..5);
+}
+
+#[test]
+fn test_dissolve_for_foreign_newtype() {
+	use std::num::Wrapping;
+
+	dissolve_for! {
+		#[dissolve(newtype)]
+		struct Wrapping<T>(T);
+	}
+
+	// Arrange
+	let wrapping = Wrapping(255u8);
+
+	// Act
+	let value = dissolve_wrapping(wrapping);
+
+	// Assert
+	assert_eq!(value, 255);
+}
+
+#[test]
+fn test_snapshot_clones_included_fields() {
+	struct NotClone;
+
+	#[derive(Dissolve)]
+	#[dissolve(snapshot = "peek")]
+	struct Session<T> {
+		#[dissolved(rename = "name")]
+		user: String,
+
+		#[dissolved(skip)]
+		handle: NotClone,
+
+		data: Vec<T>,
+	}
+
+	// Arrange
+	let session = Session { user: "erin".into(), handle: NotClone, data: vec![1, 2] };
+
+	// Act
+	let snapshot = session.peek();
+	let dissolved = session.dissolve();
+
+	// Assert
+	assert_eq!(snapshot.name, dissolved.name);
+	assert_eq!(snapshot.data, dissolved.data);
+}
+
+#[test]
+fn test_snapshot_tuple_struct() {
+	#[derive(Dissolve)]
+	#[dissolve(snapshot)]
+	struct Pair(String, #[dissolved(skip)] u8, u16);
+
+	// Arrange
+	let pair = Pair("a".into(), 1, 2);
+
+	// Act
+	let snapshot = pair.snapshot();
+
+	// Assert
+	assert_eq!(snapshot, pair.dissolve());
+	assert_eq!(snapshot, ("a".to_string(), 2));
+}
+
+#[test]
+fn test_boxed_with_generics() {
+	#[derive(Dissolve)]
+	#[dissolve(boxed, recompose)]
+	struct Large<T: Clone> {
+		buffer: [T; 64],
+
+		#[dissolved(skip)]
+		checksum: u32,
+	}
+
+	// Arrange
+	let large = Large { buffer: [7u8; 64], checksum: 1 };
+
+	// Act
+	let dissolved: Box<LargeDissolved<u8>> = large.dissolve();
+	let Large { buffer, checksum } = dissolved.into_original(2);
+
+	// Assert
+	assert_eq!(buffer, [7; 64]);
+	assert_eq!(checksum, 2);
+}
+
+#[test]
+fn test_transparent_with_marker() {
+	use std::mem::{align_of, size_of};
+
+	#[derive(Dissolve)]
+	#[dissolve(transparent)]
+	struct Handle<T> {
+		raw: u64,
+
+		#[dissolved(skip)]
+		owner: T,
+	}
+
+	// Arrange
+	let handle = Handle { raw: 42, owner: "owner".to_string() };
+
+	// Act
+	let dissolved = handle.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.raw, 42);
+	assert_eq!(size_of::<HandleDissolved<String>>(), size_of::<u64>());
+	assert_eq!(align_of::<HandleDissolved<String>>(), align_of::<u64>());
+}
+
+#[test]
+fn test_as_ref_single_included_field() {
+	#[derive(Dissolve)]
+	#[dissolve(as_ref)]
+	struct Labeled<T> {
+		value: T,
+
+		#[dissolved(skip)]
+		label: &'static str,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(as_ref, newtype)]
+	struct Meters(f64);
+
+	// Arrange
+	let labeled = Labeled { value: vec![1, 2], label: "numbers" };
+	let meters = Meters(3.5);
+
+	// Act
+	let value: &Vec<i32> = labeled.as_ref();
+	let distance: &f64 = meters.as_ref();
+
+	// Assert
+	assert_eq!(value, &[1, 2]);
+	assert_eq!(*distance, 3.5);
+	assert_eq!(labeled.dissolve().value, [1, 2]);
+}
+
+#[test]
+fn test_skipped_field_sharing_lifetime() {
+	struct Arena {
+		bytes: Vec<u8>,
+	}
+
+	// `arena` is skipped, but `'a` is still used by `data`, so the dissolved struct keeps it
+	#[derive(Dissolve)]
+	#[dissolve(prune_generics)]
+	struct Holder<'a> {
+		data: &'a [u8],
+
+		#[dissolved(skip)]
+		arena: &'a Arena,
+	}
+
+	// Arrange
+	let arena = Arena { bytes: vec![1, 2, 3] };
+	let holder = Holder { data: &arena.bytes[1..], arena: &arena };
+
+	// Act
+	let dissolved: HolderDissolved<'_> = holder.dissolve();
+
+	// Assert
+	// Dropping the skipped reference does not end the borrow of the arena
+	assert_eq!(dissolved.data, [2, 3]);
+	assert_eq!(arena.bytes.len(), 3);
+}
+
+#[test]
+fn test_multiple_container_attributes_merge() {
+	#[derive(Dissolve)]
+	#[dissolve(visibility = "pub", derive(Debug))]
+	#[dissolve(visibility = "pub(crate)", derive(Clone, PartialEq))]
+	#[dissolve(move_strategy = "destructure")]
+	struct Settings {
+		name: String,
+
+		#[dissolved(skip)]
+		revision: u32,
+	}
+
+	// Arrange
+	let settings = Settings { name: "prod".into(), revision: 3 };
+
+	// Act
+	let dissolved = settings.dissolve();
+	let cloned = dissolved.clone();
+
+	// Assert
+	assert_eq!(cloned, dissolved);
+	assert_eq!(
+		format!("{dissolved:?}"),
+		r#"SettingsDissolved { name: "prod" }"#
+	);
+}
+
+#[test]
+fn test_enum_derive() {
+	#[derive(Dissolve)]
+	#[dissolve(derive(Debug, PartialEq))]
+	enum Status {
+		Active { since: u64 },
+		Inactive,
+	}
+
+	// Arrange
+	let status = Status::Active { since: 5 };
+
+	// Act
+	let dissolved = status.dissolve();
+
+	// Assert
+	assert_eq!(dissolved, StatusDissolved::Active { since: 5 });
+	assert_ne!(Status::Inactive.dissolve(), dissolved);
+}
+
+#[test]
+fn test_method_attrs_on_complex_tuple() {
+	use std::collections::HashMap;
+
+	#[derive(Dissolve)]
+	#[dissolve(method_attrs(inline, must_use = "dissolving discards the struct"))]
+	#[allow(clippy::type_complexity)]
+	struct Index(
+		HashMap<String, Vec<(u32, u32)>>,
+		Vec<Option<(String, u32)>>,
+		#[dissolved(skip)] u64,
+		Option<Box<dyn Fn(u32) -> u32>>,
+	);
+
+	// Arrange
+	let index = Index(HashMap::new(), vec![None], 7, Some(Box::new(|x| x + 1)));
+
+	// Act
+	let (entries, slots, callback) = index.dissolve();
+
+	// Assert
+	assert!(entries.is_empty());
+	assert_eq!(slots, [None]);
+	assert_eq!(callback.map(|f| f(1)), Some(2));
+}
+
+#[test]
+fn test_field_extractors() {
+	#[derive(Dissolve)]
+	#[dissolve(field_extractors)]
+	struct Response<B> {
+		status: u16,
+
+		#[dissolved(rename = "payload")]
+		body: B,
+
+		#[dissolved(skip)]
+		latency_ms: u64,
+	}
+
+	// Arrange
+	let response = || Response { status: 200, body: vec![1u8, 2], latency_ms: 5 };
+
+	// Act
+	let status = response().dissolve().into_status();
+	let payload = response().dissolve().into_payload();
+
+	// Assert
+	assert_eq!(status, 200);
+	assert_eq!(payload, [1, 2]);
+}
+
+#[test]
+fn test_nested_groups() {
+	#[derive(Dissolve)]
+	#[dissolve(nested, recompose, derive(Debug, PartialEq))]
+	struct Customer<T> {
+		name: String,
+
+		#[dissolved(group = "home_address")]
+		street: String,
+
+		#[dissolved(skip)]
+		id: u32,
+
+		#[dissolved(group = "home_address", rename = "town")]
+		city: String,
+
+		tag: T,
+	}
+
+	// Arrange
+	let customer = Customer {
+		name: "Ada".to_string(),
+		street: "Main Street".to_string(),
+		id: 7,
+		city: "London".to_string(),
+		tag: 1u8,
+	};
+
+	// Act
+	let dissolved = customer.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.name, "Ada");
+	assert_eq!(dissolved.home_address.street, "Main Street");
+	assert_eq!(dissolved.home_address.town, "London");
+	assert_eq!(dissolved.tag, 1);
+
+	let customer = dissolved.into_original(7);
+	assert_eq!((customer.id, customer.city), (7, "London".to_string()));
+}
+
+#[test]
+fn test_two_groups_with_ungrouped_fields() {
+	#[derive(Dissolve)]
+	#[dissolve(nested)]
+	struct ServerConfig {
+		name: String,
+
+		#[dissolved(group = "network")]
+		host: String,
+
+		#[dissolved(group = "storage")]
+		data_dir: String,
+
+		#[dissolved(group = "network")]
+		port: u16,
+
+		workers: usize,
+
+		#[dissolved(group = "storage")]
+		cache_size: u64,
+	}
+
+	// Arrange
+	let config = ServerConfig {
+		name: "api".to_string(),
+		host: "localhost".to_string(),
+		data_dir: "/var/lib/api".to_string(),
+		port: 8080,
+		workers: 4,
+		cache_size: 1024,
+	};
+
+	// Act
+	let ServerConfigDissolved { name, network, storage, workers } = config.dissolve();
+	let ServerConfigNetworkDissolved { host, port } = network;
+	let ServerConfigStorageDissolved { data_dir, cache_size } = storage;
+
+	// Assert
+	assert_eq!((name, workers), ("api".to_string(), 4));
+	assert_eq!((host, port), ("localhost".to_string(), 8080));
+	assert_eq!((data_dir, cache_size), ("/var/lib/api".to_string(), 1024));
+}
+
+#[test]
+fn test_opaque() {
+	#[derive(Dissolve)]
+	#[dissolve(opaque, recompose)]
+	struct Token {
+		value: String,
+
+		#[dissolved(skip)]
+		expires_at: u64,
+	}
+
+	// Arrange
+	let token = Token { value: "secret".to_string(), expires_at: 60 };
+
+	// Act
+	let dissolved = token.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.value, "secret");
+
+	let token = dissolved.into_original(120);
+	assert_eq!((token.value, token.expires_at), ("secret".to_string(), 120));
+}
+
+#[test]
+fn test_seal() {
+	mod session {
+		use dissolve_derive::Dissolve;
+
+		#[derive(Dissolve)]
+		#[dissolve(seal, recompose, snapshot, derive(Clone, Debug, PartialEq))]
+		pub struct Session {
+			pub id: u32,
+			pub _seal: bool,
+		}
+	}
+
+	// Arrange
+	let session = session::Session { id: 1, _seal: true };
+
+	// Act
+	let snapshot = session.snapshot();
+	let dissolved = session.dissolve();
+	let session::SessionDissolved { id, _seal, .. } = dissolved.clone();
+
+	// Assert
+	assert_eq!(snapshot, dissolved);
+	assert_eq!((id, _seal), (1, true));
+	assert_eq!(dissolved.into_original().id, 1);
+}
+
+#[test]
+fn test_after_hook() {
+	use std::cell::RefCell;
+
+	thread_local! {
+		static SEEN: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(after = "Self::on_dissolve")]
+	struct Job {
+		name: String,
+
+		#[dissolved(skip)]
+		attempts: u32,
+	}
+
+	impl Job {
+		fn on_dissolve(&self) {
+			SEEN.with_borrow_mut(|seen| seen.push(format!("{}:{}", self.name, self.attempts)));
+		}
+	}
+
+	// Arrange
+	let job = Job { name: "backup".to_string(), attempts: 3 };
+
+	// Act
+	let dissolved = job.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.name, "backup");
+	assert_eq!(SEEN.with_borrow(Clone::clone), ["backup:3"]);
+}
+
+#[test]
+fn test_finalize_hook() {
+	use std::cell::RefCell;
+
+	thread_local! {
+		static SEEN: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+	}
+
+	fn record(event: String) {
+		SEEN.with_borrow_mut(|seen| seen.push(event));
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(after = "Self::on_dissolve", finalize = "check_range")]
+	struct Range {
+		#[dissolved(rename = "low")]
+		start: u32,
+		end: u32,
+	}
+
+	impl Range {
+		fn on_dissolve(&self) {
+			record("after".to_string());
+		}
+	}
+
+	fn check_range(range: &RangeDissolved) {
+		assert!(range.low <= range.end, "inverted range");
+		record(format!("finalize:{}..{}", range.low, range.end));
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(finalize = "check_pair")]
+	struct Pair(u8, #[dissolved(skip)] u8, u8);
+
+	fn check_pair(pair: &(u8, u8)) {
+		record(format!("finalize:{pair:?}"));
+	}
+
+	// Arrange
+	let range = Range { start: 2, end: 5 };
+	let pair = Pair(1, 2, 3);
+
+	// Act
+	let range = range.dissolve();
+	let pair = pair.dissolve();
+
+	// Assert
+	assert_eq!((range.low, range.end), (2, 5));
+	assert_eq!(pair, (1, 3));
+	assert_eq!(
+		SEEN.with_borrow(Clone::clone),
+		["after", "finalize:2..5", "finalize:(1, 3)"]
+	);
+}
+
+#[test]
+fn test_dissolve_dynamic() {
+	#[derive(Dissolve)]
+	#[dissolve(dynamic)]
+	struct Plugin<C> {
+		name: String,
+
+		#[dissolved(rename = "settings")]
+		config: C,
+
+		#[dissolved(skip)]
+		handle: u64,
+	}
+
+	// Arrange
+	let plugin = Plugin { name: "audio".to_string(), config: vec![44_100u32], handle: 3 };
+
+	// Act
+	let mut fields = plugin.dissolve_dynamic();
+
+	// Assert
+	assert_eq!(fields.len(), 2);
+
+	let name = fields.remove("name").unwrap().downcast::<String>().unwrap();
+	assert_eq!(*name, "audio");
+
+	let settings = fields.remove("settings").unwrap().downcast::<Vec<u32>>().unwrap();
+	assert_eq!(*settings, [44_100]);
+}
+
+#[test]
+fn test_enum_variants_rename_to_same_name() {
+	#[derive(Dissolve)]
+	enum Reading {
+		Temperature {
+			#[dissolved(rename = "value")]
+			celsius: f64,
+		},
+		Humidity {
+			#[dissolved(rename = "value")]
+			percent: f64,
+
+			#[dissolved(skip)]
+			value: u8,
+		},
+	}
+
+	// Arrange
+	let readings = [
+		Reading::Temperature { celsius: 21.5 },
+		Reading::Humidity { percent: 40.0, value: 1 },
+	];
+
+	// Act
+	let values = readings.map(|reading| match reading.dissolve() {
+		ReadingDissolved::Temperature { value } | ReadingDissolved::Humidity { value } => value,
+	});
+
+	// Assert
+	assert_eq!(values, [21.5, 40.0]);
+}
+
+#[test]
+fn test_dissolve_tagged() {
+	#[derive(Dissolve)]
+	#[dissolve(tagged, derive(Debug, PartialEq))]
+	struct Event<P, M> {
+		#[dissolved(rename = "event_id")]
+		id: u64,
+
+		payload: P,
+
+		#[dissolved(skip)]
+		_meta: M,
+	}
+
+	// Arrange
+	let event = Event { id: 9, payload: "started", _meta: () };
+
+	// Act
+	let fields = event.dissolve_tagged();
+
+	// Assert
+	assert_eq!(
+		fields,
+		[EventField::EventId(9), EventField::Payload("started")]
+	);
+}
+
+#[test]
+fn test_transition_to() {
+	mod states {
+		pub struct Processing<T> {
+			pub request_id: u64,
+			pub body: T,
+		}
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(transition_to = "states::Processing<T>", via(request_id, body))]
+	struct PendingRequest<T> {
+		request_id: u64,
+
+		#[dissolved(rename = "body")]
+		payload: T,
+
+		#[dissolved(skip)]
+		_received_at: std::time::Instant,
+	}
+
+	// Arrange
+	let pending = PendingRequest {
+		request_id: 9,
+		payload: vec![1u8, 2],
+		_received_at: std::time::Instant::now(),
+	};
+
+	// Act
+	let processing = pending.into_processing();
+
+	// Assert
+	assert_eq!(processing.request_id, 9);
+	assert_eq!(processing.body, vec![1, 2]);
+}
+
+#[test]
+fn test_tuple_from() {
+	#[derive(Dissolve)]
+	#[dissolve(tuple_from)]
+	struct Entry<V> {
+		key: String,
+
+		#[dissolved(skip)]
+		version: u32,
+
+		#[dissolved(rename = "val")]
+		value: V,
+	}
+
+	// Arrange
+	let entry = Entry { key: "retries".to_string(), version: 2, value: 3u8 };
+
+	// Act
+	let (key, value): (String, u8) = entry.into();
+
+	// Assert
+	assert_eq!((key.as_str(), value), ("retries", 3));
+}
+
+#[test]
+fn test_serde_with_feature() {
+	// Arrange
+	let manifest = concat!(
+		env!("CARGO_MANIFEST_DIR"),
+		"/tests/serde_feature/Cargo.toml"
+	);
+	let target_dir = concat!(env!("CARGO_TARGET_TMPDIR"), "/serde_feature");
+
+	// Act
+	let output = std::process::Command::new(env!("CARGO"))
+		.args([
+			"run",
+			"--quiet",
+			"--manifest-path",
+			manifest,
+			"--target-dir",
+			target_dir,
+		])
+		.output()
+		.unwrap();
+
+	// Assert
+	assert!(
+		output.status.success(),
+		"{}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	assert_eq!(
+		String::from_utf8(output.stdout).unwrap().trim_end(),
+		r#"{"host_name":"localhost","port":8080}"#
+	);
+}
+
+#[test]
+fn test_dissolve_to_json_round_trip() {
+	// `derive(...)` rather than `serde`, whose derives depend on a `serde` feature of this crate
+	#[derive(Dissolve, serde::Serialize)]
+	#[dissolve(
+		to_json,
+		derive(Debug, PartialEq, serde::Serialize, serde::Deserialize),
+		keep_attrs(doc, serde)
+	)]
+	struct Config {
+		#[serde(rename = "host_name")]
+		host: String,
+
+		port: u16,
+
+		#[dissolved(skip)]
+		password: String,
+	}
+
+	// Arrange
+	let config =
+		Config { host: "localhost".to_string(), port: 8080, password: "secret".to_string() };
+
+	// Act
+	let json = config.dissolve_to_json().unwrap();
+	let parsed: ConfigDissolved = serde_json::from_str(&json).unwrap();
+
+	// Assert
+	assert_eq!(json, r#"{"host_name":"localhost","port":8080}"#);
+	assert_eq!(
+		parsed,
+		ConfigDissolved { host: "localhost".to_string(), port: 8080 }
+	);
+}
+
+#[test]
+fn test_dissolve_to_json_enum_and_tuple_struct() {
+	#[derive(Dissolve, serde::Serialize)]
+	#[dissolve(to_json, derive(serde::Serialize), keep_attrs(serde))]
+	enum Shape {
+		Circle {
+			#[serde(rename = "r")]
+			radius: f64,
+		},
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(to_json)]
+	struct Point(i32, #[dissolved(skip)] u8, i32);
+
+	// Act
+	let shape = Shape::Circle { radius: 1.5 }.dissolve_to_json().unwrap();
+	let point = Point(1, 0, -2).dissolve_to_json().unwrap();
+
+	// Assert
+	assert_eq!(shape, r#"{"Circle":{"r":1.5}}"#);
+	assert_eq!(point, "[1,-2]");
+}
+
+#[test]
+fn test_cfg_enabled() {
+	#[derive(Dissolve)]
+	#[dissolve(cfg = "all(test, not(any()))", recompose)]
+	struct Sample {
+		value: u32,
+
+		#[dissolved(skip)]
+		unit: char,
+	}
+
+	// Arrange
+	let sample = Sample { value: 3, unit: 'm' };
+
+	// Act
+	let dissolved: SampleDissolved = sample.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.value, 3);
+	assert_eq!(dissolved.into_original('s').unit, 's');
+}
+
+#[test]
+fn test_cfg_disabled() {
+	// `any()` never holds, so no `dissolve` is generated to clash with this one
+	#[derive(Dissolve)]
+	#[dissolve(cfg = "any()")]
+	struct Sample {
+		value: u32,
+	}
+
+	impl Sample {
+		fn dissolve(self) -> u32 {
+			self.value * 2
+		}
+	}
+
+	// Arrange
+	let sample = Sample { value: 3 };
+
+	// Act
+	let value = sample.dissolve();
+
+	// Assert
+	assert_eq!(value, 6);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_field_alias() {
+	#[derive(Dissolve)]
+	struct Server {
+		#[dissolved(rename = "host", alias = "hostname")]
+		hostname: String,
+
+		port: u16,
+	}
+
+	// Arrange
+	let server = Server { hostname: "example.org".to_string(), port: 443 };
+
+	// Act
+	let dissolved = server.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.hostname(), "example.org");
+	assert_eq!(dissolved.host, "example.org");
+	assert_eq!(dissolved.port, 443);
+}
+
+#[test]
+fn test_enum_generics_only_used_by_skipped_fields() {
+	#[derive(Dissolve)]
+	enum Job<'a, T: Clone> {
+		Ready {
+			#[dissolved(skip)]
+			input: T,
+			id: u32,
+		},
+		Borrowed(&'a str, #[dissolved(skip)] T),
+	}
+
+	// Arrange
+	let jobs = [
+		Job::Ready { input: 1.5, id: 4 },
+		Job::Borrowed("queued", 2.5),
+	];
+
+	// Act
+	let dissolved: [JobDissolved<'_>; 2] = jobs.map(Job::dissolve);
+
+	// Assert
+	assert!(matches!(dissolved[0], JobDissolved::Ready { id: 4 }));
+	assert!(matches!(dissolved[1], JobDissolved::Borrowed("queued")));
+}
+
+#[test]
+fn test_boxed_self() {
+	#[derive(Dissolve)]
+	#[dissolve(boxed_self, move_strategy = "destructure")]
+	struct Builder<T> {
+		items: Vec<T>,
+
+		#[dissolved(skip)]
+		scratch: String,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(boxed_self)]
+	enum Step {
+		Push(u8),
+		Finish { count: usize },
+	}
+
+	// Arrange
+	let builder = Box::new(Builder { items: vec![1, 2], scratch: String::new() });
+	let step = Box::new(Step::Finish { count: 2 });
+
+	// Act
+	let dissolved = builder.dissolve();
+	let step = step.dissolve();
+
+	// Assert
+	assert_eq!(dissolved.items, [1, 2]);
+	assert!(matches!(step, StepDissolved::Finish { count: 2 }));
+}
+
+#[test]
+fn test_dissolve_arc() {
+	use std::sync::Arc;
+
+	#[derive(Debug, Dissolve)]
+	#[dissolve(from_arc, derive(Debug))]
+	struct Config {
+		name: String,
+
+		#[dissolved(skip)]
+		_cache: Vec<u8>,
+	}
+
+	// Arrange
+	let config = Arc::new(Config { name: "prod".to_string(), _cache: Vec::new() });
+	let shared = Arc::clone(&config);
+
+	// Act
+	let config = Config::dissolve_arc(config).expect_err("dissolved while still shared");
+	drop(shared);
+	let dissolved = Config::dissolve_arc(config).expect("not dissolved by its single owner");
+
+	// Assert
+	assert_eq!(dissolved.name, "prod");
+}
+
+#[test]
+fn test_dissolve_rc() {
+	use std::rc::Rc;
+
+	#[derive(Debug, Dissolve)]
+	#[dissolve(from_rc, derive(Debug))]
+	enum Node {
+		Leaf(u8),
+		Branch { children: Vec<u8> },
+	}
+
+	// Arrange
+	let node = Rc::new(Node::Branch { children: vec![1, 2] });
+	let shared = Rc::clone(&node);
+
+	// Act
+	let node = Node::dissolve_rc(node).expect_err("dissolved while still shared");
+	drop(shared);
+	let dissolved = Node::dissolve_rc(node).expect("not dissolved by its single owner");
+
+	// Assert
+	assert!(matches!(dissolved, NodeDissolved::Branch { children } if children == [1, 2]));
+}
+
+#[test]
+fn test_copy_view() {
+	#[derive(Dissolve)]
+	#[dissolve(copy_view)]
+	struct Point<T> {
+		#[dissolved(rename = "horizontal")]
+		x: T,
+		y: T,
+
+		#[dissolved(skip)]
+		_label: String,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(copy_view)]
+	struct Rgb(u8, u8, u8);
+
+	// Arrange
+	let point = Point { x: 1.5, y: -2.0, _label: "origin".to_string() };
+	let rgb = Rgb(255, 128, 0);
+
+	// Act
+	let view = point.dissolve_ref();
+	let channels = rgb.dissolve_ref();
+
+	// Assert
+	assert_eq!((view.horizontal, view.y), (1.5, -2.0));
+	assert_eq!(channels, (255, 128, 0));
+	assert_eq!(point.dissolve().horizontal, 1.5);
+	assert_eq!(rgb.dissolve(), (255, 128, 0));
+}
+
+#[test]
+fn test_drain() {
+	#[derive(Dissolve)]
+	#[dissolve(drain)]
+	struct Buffer<T> {
+		#[dissolved(rename = "items")]
+		pending: Vec<T>,
+		flushes: u32,
+
+		#[dissolved(skip)]
+		capacity: usize,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(drain)]
+	struct Pair(String, #[dissolved(skip)] u8, Option<char>);
+
+	// Arrange
+	let mut buffer = Buffer { pending: vec!['a', 'b'], flushes: 3, capacity: 16 };
+	let mut pair = Pair("left".to_string(), 7, Some('r'));
+
+	// Act
+	let drained = buffer.drain();
+	let (left, right) = pair.drain();
+
+	// Assert
+	assert_eq!((drained.items, drained.flushes), (vec!['a', 'b'], 3));
+	assert!(buffer.pending.is_empty());
+	assert_eq!(buffer.flushes, 0);
+	assert_eq!(buffer.capacity, 16);
+	assert_eq!((left, right), ("left".to_string(), Some('r')));
+	assert_eq!((pair.0.as_str(), pair.1, pair.2), ("", 7, None));
+}
+
+#[test]
+fn test_generated_bindings_use_field_names() {
+	// The generated code binds fields by their own names, or `field_{index}` for tuple fields,
+	// instead of hygienic temporaries, so it must not mix them up when the names overlap
+	#[derive(Dissolve)]
+	#[dissolve(move_strategy = "destructure")]
+	struct Swapped {
+		#[dissolved(rename = "second")]
+		first: u8,
+
+		#[dissolved(rename = "first")]
+		second: u8,
+
+		#[dissolved(skip)]
+		_field_0: u8,
+	}
+
+	#[derive(Dissolve)]
+	enum Event {
+		Moved { field_1: u8, dissolved: u8 },
+		Resized(u8, #[dissolved(skip)] u8, u8),
+	}
+
+	// Arrange
+	let swapped = Swapped { first: 1, second: 2, _field_0: 0 };
+	let moved = Event::Moved { field_1: 3, dissolved: 4 };
+	let resized = Event::Resized(5, 6, 7);
+
+	// Act
+	let swapped = swapped.dissolve();
+	let moved = moved.dissolve();
+	let resized = resized.dissolve();
+
+	// Assert
+	assert_eq!((swapped.second, swapped.first), (1, 2));
+	assert!(matches!(
+		moved,
+		EventDissolved::Moved { field_1: 3, dissolved: 4 }
+	));
+	assert!(matches!(resized, EventDissolved::Resized(5, 7)));
+}
+
+#[test]
+fn test_attribute_macro() {
+	#[dissolve_derive::dissolve(visibility = "pub(crate)")]
+	#[derive(Debug, PartialEq)]
+	#[dissolve(snapshot)]
+	struct Account {
+		#[dissolved(rename = "owner")]
+		name: String,
+
+		#[dissolved(skip)]
+		balance: u64,
+	}
+
+	#[dissolve_derive::dissolve]
+	enum Command {
+		Run { program: String },
+		Stop(#[dissolved(skip)] u8),
+	}
+
+	// Arrange
+	let account = Account { name: "ada".to_string(), balance: 10 };
+	let command = Command::Run { program: "ls".to_string() };
+
+	// Act
+	let snapshot = account.snapshot();
+	let dissolved = account.dissolve();
+	let command = command.dissolve();
+
+	// Assert
+	assert_eq!(snapshot.owner, "ada");
+	assert_eq!(dissolved.owner, "ada");
+	assert!(matches!(command, CommandDissolved::Run { program } if program == "ls"));
+	assert!(matches!(
+		Command::Stop(1).dissolve(),
+		CommandDissolved::Stop()
+	));
+}
+
+#[test]
+fn test_field_with_conversion() {
+	use std::{marker::PhantomPinned, pin::Pin};
+
+	struct Node {
+		value: u32,
+		_pinned: PhantomPinned,
+	}
+
+	fn into_raw(node: Pin<Box<Node>>) -> *mut Node {
+		// SAFETY: the node is never moved out of its allocation, which the pointer keeps alive
+		Box::into_raw(unsafe { Pin::into_inner_unchecked(node) })
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(move_strategy = "destructure")]
+	struct Graph {
+		name: String,
+
+		#[dissolved(with = "into_raw", ty = "*mut Node", rename = "root_ptr")]
+		root: Pin<Box<Node>>,
+	}
+
+	#[derive(Dissolve)]
+	struct Celsius(
+		#[dissolved(with = "f64::from", ty = "f64")] f32,
+		#[dissolved(skip)] u8,
+	);
+
+	#[derive(Dissolve)]
+	enum Reading {
+		Named {
+			#[dissolved(with = "u64::from", ty = "u64")]
+			id: u32,
+		},
+		Raw(#[dissolved(with = "Vec::into_boxed_slice", ty = "Box<[u8]>")] Vec<u8>),
+	}
+
+	// Arrange
+	let root = Box::pin(Node { value: 7, _pinned: PhantomPinned });
+	let address: *const Node = &*root;
+	let graph = Graph { name: "tree".to_string(), root };
+
+	// Act
+	let dissolved = graph.dissolve();
+	let celsius = Celsius(21.5, 0).dissolve();
+	let named = Reading::Named { id: 3 }.dissolve();
+	let raw = Reading::Raw(vec![1, 2]).dissolve();
+
+	// Assert
+	assert_eq!(dissolved.name, "tree");
+	assert!(std::ptr::eq(dissolved.root_ptr, address));
+	// SAFETY: the pointer comes from `Box::into_raw` and is only turned back into a box once
+	let root = unsafe { Pin::new_unchecked(Box::from_raw(dissolved.root_ptr)) };
+	assert_eq!(root.value, 7);
+	assert_eq!(celsius, (21.5,));
+	assert!(matches!(named, ReadingDissolved::Named { id: 3_u64 }));
+	assert!(matches!(raw, ReadingDissolved::Raw(bytes) if *bytes == [1, 2]));
+}
+
+#[test]
+fn test_trait_object_fields() {
+	type Callback<'a> = Box<dyn FnMut(&str) + 'a>;
+
+	#[derive(Dissolve)]
+	struct Handlers<'a, T: Send> {
+		handler: Box<dyn Fn(u32) -> u32 + Send + Sync + 'static>,
+
+		#[dissolved(rename = "on_event")]
+		callback: Callback<'a>,
+
+		filter: &'a (dyn Fn(&T) -> bool + Send + 'a),
+
+		#[dissolved(skip)]
+		_name: String,
+	}
+
+	fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+	// Arrange
+	let mut events = Vec::new();
+	let is_even = |value: &u32| value.is_multiple_of(2);
+	let handlers = Handlers {
+		handler: Box::new(|value| value * 2),
+		callback: Box::new(|event| events.push(event.to_string())),
+		filter: &is_even,
+		_name: "handlers".to_string(),
+	};
+
+	// Act
+	let HandlersDissolved { handler, mut on_event, filter } = handlers.dissolve();
+	on_event("started");
+	drop(on_event);
+
+	// Assert
+	assert_send_sync(&handler);
+	assert_eq!(handler(21), 42);
+	assert!(filter(&4));
+	assert_eq!(events, ["started"]);
+}
+
+#[test]
+fn test_no_auto_doc() {
+	#[derive(Dissolve)]
+	#[dissolve(no_auto_doc, method_attrs(doc = "Take the settings apart."))]
+	struct Settings(u8, #[dissolved(skip)] bool);
+
+	#[derive(Dissolve)]
+	#[dissolve(no_auto_doc)]
+	enum Mode {
+		Fast { level: u8 },
+	}
+
+	// Act
+	let settings = Settings(3, true).dissolve();
+	let mode = Mode::Fast { level: 1 }.dissolve();
+
+	// Assert
+	assert_eq!(settings, (3,));
+	assert!(matches!(mode, ModeDissolved::Fast { level: 1 }));
+}
+
+#[test]
+fn test_terminal_generates_nothing() {
+	#[derive(Dissolve)]
+	#[dissolve(terminal)]
+	struct Reading {
+		celsius: i16,
+	}
+
+	// A generated `dissolve` would clash with this one
+	impl Reading {
+		fn dissolve(self) -> i16 {
+			self.celsius
+		}
+	}
+
+	// Act
+	let celsius = Reading { celsius: -4 }.dissolve();
+
+	// Assert
+	assert_eq!(celsius, -4);
+}
+
+#[test]
+fn test_method_name_avoids_existing_dissolve() {
+	#[derive(Dissolve)]
+	#[dissolve(method = "into_parts", map, from_rc)]
+	struct Solution {
+		solvent: String,
+
+		#[dissolved(skip)]
+		concentration: f64,
+	}
+
+	impl Solution {
+		fn dissolve(&mut self, amount: f64) {
+			self.concentration += amount;
+		}
+	}
+
+	// Arrange
+	let mut solution = Solution { solvent: "water".to_string(), concentration: 0.0 };
+	solution.dissolve(0.5);
+
+	// Act
+	let SolutionDissolved { solvent } = solution.into_parts();
+	let shared = std::rc::Rc::new(Solution { solvent: "ethanol".to_string(), concentration: 1.0 });
+	let mapped = Solution { solvent: "oil".to_string(), concentration: 0.0 }
+		.map_dissolved(|dissolved| dissolved.solvent.len());
+
+	// Assert
+	assert_eq!(solvent, "water");
+	assert_eq!(
+		Solution::dissolve_rc(shared).ok().map(|d| d.solvent),
+		Some("ethanol".to_string())
+	);
+	assert_eq!(mapped, 3);
+}
+
+#[test]
+fn test_tuple_struct_output_struct() {
+	#[derive(Dissolve)]
+	#[dissolve(output = "struct", recompose, move_strategy = "destructure")]
+	struct Rgba<T>(
+		T,
+		T,
+		#[dissolved(rename = "blue")] T,
+		#[dissolved(skip)] f32,
+	);
+
+	// Arrange
+	let color = Rgba(255u8, 128, 0, 0.5);
+
+	// Act
+	let mut dissolved = color.dissolve();
+	dissolved.field_1 = 64;
+	let RgbaDissolved { field_0, field_1, blue } = dissolved;
+	let Rgba(red, green, rebuilt_blue, alpha) =
+		RgbaDissolved { field_0, field_1, blue }.into_original(1.0);
+
+	// Assert
+	assert_eq!((field_0, field_1, blue), (255, 64, 0));
+	assert_eq!((red, green, rebuilt_blue, alpha), (255, 64, 0, 1.0));
+}
+
+#[test]
+fn test_tuple_struct_output_struct_keeps_index_names() {
+	#[derive(Dissolve)]
+	#[dissolve(output = "struct", manually_drop, snapshot)]
+	struct Packet(
+		u16,
+		#[dissolved(skip)] Vec<u8>,
+		#[dissolved(skip, keep)] String,
+		u32,
+	);
+
+	// Arrange
+	let packet = Packet(7, vec![1, 2], "checksum".to_string(), 42);
+
+	// Act
+	let PacketDissolved { field_0, field_3 } = packet.snapshot();
+	let (dissolved, carrier) = packet.dissolve();
+
+	// Assert
+	assert_eq!((*field_0, *field_3), (7, 42));
+	assert_eq!((*dissolved.field_0, *dissolved.field_3), (7, 42));
+	assert_eq!(carrier.field_2, "checksum");
+}
+
+#[test]
+fn test_cow_fields() {
+	use std::{borrow::Cow, path::PathBuf};
+
+	#[derive(Dissolve)]
+	struct Document {
+		#[dissolved(cow)]
+		title: String,
+
+		#[dissolved(cow)]
+		tags: Vec<u32>,
+
+		#[dissolved(cow)]
+		path: PathBuf,
+	}
+
+	fn shout(text: Cow<'_, str>) -> String {
+		text.to_uppercase()
+	}
+
+	// Arrange
+	let document = Document {
+		title: "notes".to_string(),
+		tags: vec![1, 2],
+		path: PathBuf::from("/tmp/notes"),
+	};
+
+	// Act
+	let DocumentDissolved { title, tags, path } = document.dissolve();
+
+	// Assert
+	assert!(matches!(title, Cow::Owned(_)));
+	assert_eq!(shout(title), "NOTES");
+	assert_eq!(tags, Cow::<[u32]>::Borrowed(&[1, 2]));
+	assert_eq!(path.to_str(), Some("/tmp/notes"));
+}
+
+#[test]
+fn test_rename_all() {
+	#[derive(Dissolve)]
+	#[dissolve(rename_all = "prefix:raw_")]
+	struct Sample {
+		value: u16,
+
+		#[dissolved(rename_with = "suffix:_at")]
+		taken: u64,
+
+		#[dissolved(rename = "channel")]
+		source: u8,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(output = "struct", rename_all = "strip_prefix:field_|prefix:channel_")]
+	struct Stereo(i16, #[dissolved(skip)] u32, i16);
+
+	#[derive(Dissolve)]
+	#[dissolve(rename_all = "upper")]
+	struct Pair(u8, u8);
+
+	#[derive(Dissolve)]
+	#[dissolve(rename_all = "upper", recompose, field_extractors)]
+	struct Limits {
+		low: u8,
+
+		#[dissolved(skip)]
+		high: u8,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(rename_all = "upper")]
+	enum Reading {
+		Level { volts: u8 },
+	}
+
+	// Act
+	let SampleDissolved { raw_value, taken_at, channel } =
+		Sample { value: 1, taken: 2, source: 3 }.dissolve();
+	let StereoDissolved { channel_0, channel_2 } = Stereo(-1, 0, 1).dissolve();
+	let (first, second) = Pair(4, 5).dissolve();
+	let low = Limits { low: 6, high: 7 }.dissolve().into_LOW();
+	let ReadingDissolved::Level { VOLTS: volts } = Reading::Level { volts: 8 }.dissolve();
+
+	// Assert
+	assert_eq!((raw_value, taken_at, channel), (1, 2, 3));
+	assert_eq!((channel_0, channel_2), (-1, 1));
+	assert_eq!((first, second), (4, 5));
+	assert_eq!((low, volts), (6, 8));
+}
+
+#[test]
+fn test_track_caller_reaches_finalize_hook() {
+	use std::{cell::Cell, panic::Location, rc::Rc};
+
+	thread_local! {
+		static CHECKED_AT: Cell<u32> = const { Cell::new(0) };
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(finalize = "check_ratio", track_caller, map, from_rc)]
+	struct Ratio {
+		numerator: u32,
+		denominator: u32,
+	}
+
+	#[track_caller]
+	fn check_ratio(ratio: &RatioDissolved) {
+		assert_ne!(ratio.denominator, 0, "zero denominator");
+		CHECKED_AT.set(Location::caller().line());
+	}
+
+	// Arrange
+	let (first, second) = (
+		Ratio { numerator: 1, denominator: 2 },
+		Ratio { numerator: 3, denominator: 4 },
+	);
+	let third = Rc::new(Ratio { numerator: 5, denominator: 6 });
+
+	// Act
+	let (dissolved, line) = (first.dissolve(), line!());
+	let dissolve_line = CHECKED_AT.get();
+	let (mapped, map_line) = (second.map_dissolved(|ratio| ratio.numerator), line!());
+	let map_dissolved_line = CHECKED_AT.get();
+	let (shared, rc_line) = (Ratio::dissolve_rc(third), line!());
+	let dissolve_rc_line = CHECKED_AT.get();
+
+	// Assert
+	assert_eq!((dissolved.numerator, dissolved.denominator), (1, 2));
+	assert_eq!(mapped, 3);
+	assert_eq!(shared.ok().map(|ratio| ratio.numerator), Some(5));
+	assert_eq!(dissolve_line, line);
+	assert_eq!(map_dissolved_line, map_line);
+	assert_eq!(dissolve_rc_line, rc_line);
+}
+
+#[test]
+fn test_feature_rich_function_local_struct() {
+	use std::rc::Rc;
+
+	#[derive(Dissolve)]
+	#[dissolve(
+		recompose,
+		nested,
+		derive(Debug, Clone, PartialEq),
+		map,
+		snapshot,
+		from_rc,
+		tuple_from,
+		finalize = "check_job",
+		method = "into_parts"
+	)]
+	#[derive(Clone)]
+	struct Job {
+		#[dissolved(rename = "job_id")]
+		id: u32,
+
+		#[dissolved(group = "schedule")]
+		cron: String,
+
+		#[dissolved(group = "schedule")]
+		retries: u8,
+
+		#[dissolved(skip, keep_for_rebuild)]
+		secret: String,
+
+		#[dissolved(skip)]
+		runs: u64,
+	}
+
+	fn check_job((job, _): &(JobDissolved, JobCarrier)) {
+		assert!(!job.schedule.cron.is_empty());
+	}
+
+	// Arrange
+	let job = Job { id: 1, cron: "* * * * *".into(), retries: 3, secret: "s".into(), runs: 9 };
+
+	// Act
+	let snapshot = job.snapshot();
+	let (dissolved, carrier) = job.clone().into_parts();
+	let tuple: (u32, String, u8) = job.clone().into();
+	let retries = job.clone().map_dissolved(|(dissolved, _)| dissolved.schedule.retries);
+	let shared = Job::dissolve_rc(Rc::new(job)).ok().map(|(dissolved, _)| dissolved.job_id);
+	let rebuilt = dissolved.clone().into_original(carrier, 0);
+
+	// Assert
+	assert_eq!(snapshot, dissolved);
+	assert_eq!(tuple, (1, "* * * * *".to_string(), 3));
+	assert_eq!((retries, shared), (3, Some(1)));
+	assert_eq!(
+		(rebuilt.id, rebuilt.secret, rebuilt.runs),
+		(1, "s".to_string(), 0)
+	);
+}
+
+#[test]
+fn test_assert_size() {
+	#[derive(Dissolve)]
+	#[dissolve(assert_size)]
+	struct Header {
+		version: u8,
+		flags: u16,
+
+		#[dissolved(skip)]
+		checksum: u64,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(assert_size, recompose)]
+	struct Frame<'a, T> {
+		payload: &'a [T],
+
+		#[dissolved(skip, keep_for_rebuild)]
+		sequence: u32,
+	}
+
+	#[derive(Dissolve)]
+	#[dissolve(assert_size)]
+	struct Pixel(u8, u8, #[dissolved(skip)] u8);
+
+	// Arrange
+	let data = [1u16, 2];
+
+	// Act
+	let HeaderDissolved { version, flags } =
+		Header { version: 1, flags: 2, checksum: 3 }.dissolve();
+	let (frame, carrier) = Frame { payload: &data, sequence: 4 }.dissolve();
+	let pixel = Pixel(5, 6, 7).dissolve();
+
+	// Assert
+	assert_eq!((version, flags), (1, 2));
+	assert_eq!(frame.payload, [1, 2]);
+	assert_eq!(frame.into_original(carrier).sequence, 4);
+	assert_eq!(pixel, (5, 6));
+}
@@ -0,0 +1,19 @@
+//! Prints the JSON of a dissolved struct whose serde attributes are forwarded behind
+//! `#[cfg_attr(feature = "serde", ...)]`, which is enabled in this crate.
+
+use dissolve_derive::Dissolve;
+
+#[derive(Dissolve, serde::Serialize)]
+#[dissolve(serde)]
+struct Config {
+	#[serde(rename = "host_name")]
+	host: String,
+
+	port: u16,
+}
+
+fn main() {
+	let config = Config { host: "localhost".to_string(), port: 8080 };
+
+	println!("{}", serde_json::to_string(&config.dissolve()).unwrap());
+}
@@ -0,0 +1,34 @@
+//! `#[dissolve(serde)]` in a crate without a `serde` feature.
+//!
+//! The generated `cfg_attr` checks the `serde` feature, which this crate does not declare. The
+//! `cfg` is checked before the lint levels of the generated items are known, so only a
+//! crate-level `allow` silences `unexpected_cfgs`.
+
+#![allow(unexpected_cfgs)]
+
+use dissolve_derive::Dissolve;
+
+#[test]
+fn test_serde_without_feature() {
+	// This crate has no `serde` feature, so the serde derives and attributes are left out
+	#[derive(Dissolve, serde::Serialize)]
+	#[dissolve(serde, derive(Debug))]
+	struct Config {
+		#[serde(rename = "host_name")]
+		host: String,
+
+		port: u16,
+	}
+
+	// Arrange
+	let config = Config { host: "localhost".to_string(), port: 8080 };
+
+	// Act
+	let dissolved = config.dissolve();
+
+	// Assert
+	assert_eq!(
+		format!("{dissolved:?}"),
+		r#"ConfigDissolved { host: "localhost", port: 8080 }"#
+	);
+}